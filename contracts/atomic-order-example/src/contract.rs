@@ -3,14 +3,12 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{BankMsg, Coin, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, Uint128};
 use cw2::set_contract_version;
 use injective_cosmwasm::{
-    create_spot_market_order_msg, get_default_subaccount_id_for_checked_address,
-    InjectiveMsgWrapper, InjectiveQuerier, InjectiveQueryWrapper, OrderType, SpotOrder,
+    create_spot_market_order_msg, get_default_subaccount_id_for_checked_address, parse_reply,
+    InjectiveMsgWrapper, InjectiveQuerier, InjectiveQueryWrapper, OrderType, SpotMarketOrderReply,
+    SpotOrder,
 };
 use injective_math::FPDecimal;
-use injective_std::types::injective::exchange::v1beta1 as Exchange;
-use prost::Message;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg};
@@ -145,34 +143,16 @@ fn handle_atomic_order_reply(
     _env: Env,
     msg: Reply,
 ) -> Result<Response<InjectiveMsgWrapper>, ContractError> {
-    let dec_scale_factor: FPDecimal = FPDecimal::from(1000000000000000000_i128);
     let id = msg.id;
-
-    let binding = msg
-        .result
-        .into_result()
-        .map_err(ContractError::SubMsgFailure)
-        .unwrap();
-
-    let first_messsage = binding.msg_responses.first();
-
-    let order_response = Exchange::MsgCreateSpotMarketOrderResponse::decode(
-        first_messsage.unwrap().value.as_slice(),
-    )
-    .map_err(|err| ContractError::ReplyParseFailure {
+    let SpotMarketOrderReply {
+        quantity,
+        price,
+        fee,
+        ..
+    } = parse_reply(msg).map_err(|err| ContractError::ReplyParseFailure {
         id,
         err: err.to_string(),
-    })
-    .unwrap();
-
-    let trade_data = order_response
-        .results
-        .ok_or_else(|| ContractError::SubMsgFailure("No trade data".to_owned()))
-        .unwrap();
-
-    let quantity = FPDecimal::from_str(&trade_data.quantity)? / dec_scale_factor;
-    let price = FPDecimal::from_str(&trade_data.price)? / dec_scale_factor;
-    let fee = FPDecimal::from_str(&trade_data.fee)? / dec_scale_factor;
+    })?;
 
     let config = STATE.load(deps.storage)?;
 
@@ -217,6 +217,7 @@ fn test_query_trader_spot_orders() {
             fillable: human_to_dec("5.1", BASE_DECIMALS),
             isBuy: false,
             order_hash: "".to_string(),
+            cid: None,
         };
         assert_eq!(orders[0].price, expected_orders.price);
         assert_eq!(orders[0].quantity, expected_orders.quantity);
@@ -238,6 +239,7 @@ fn test_query_trader_spot_orders() {
             fillable: human_to_dec("0.5", BASE_DECIMALS),
             isBuy: true,
             order_hash: "".to_string(),
+            cid: None,
         };
         assert_eq!(orders[0].price, expected_order.price);
         assert_eq!(orders[0].quantity, expected_order.quantity);
@@ -469,6 +471,7 @@ fn test_query_spot_orders_to_cancel_up_to_amount() {
             fillable: human_to_dec("1", BASE_DECIMALS),
             isBuy: true,
             order_hash: "".to_string(),
+            cid: None,
         };
         assert_eq!(orders[0].price, expected_order.price);
         assert_eq!(orders[0].quantity, expected_order.quantity);
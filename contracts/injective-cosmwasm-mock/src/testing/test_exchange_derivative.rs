@@ -208,6 +208,7 @@ fn test_query_trader_derivative_orders() {
         fillable: FPDecimal::must_from_str("1"),
         isBuy: false,
         order_hash: "".to_string(),
+        cid: None,
     };
     assert_eq!(orders[0].price, expected_order.price);
     assert_eq!(orders[0].quantity, expected_order.quantity);
@@ -1,9 +1,7 @@
-use cosmwasm_std::{AnyMsg, CosmosMsg, StdResult};
-use injective_cosmwasm::{FullDerivativeMarket, InjectiveMsgWrapper, OrderType, SpotMarket};
+use cosmwasm_std::{Addr, AnyMsg, CosmosMsg, StdResult};
+use injective_cosmwasm::{DerivativeOrder, FullDerivativeMarket, InjectiveMsgWrapper, OrderType, SpotMarket, SpotOrder, SubaccountId};
 use injective_math::FPDecimal;
-use injective_std::types::injective::exchange::v1beta1::{
-    DerivativeOrder, MsgCreateDerivativeLimitOrder, MsgCreateSpotLimitOrder, OrderInfo, SpotOrder,
-};
+use injective_std::types::injective::exchange::v1beta1::{MsgCreateDerivativeLimitOrder, MsgCreateSpotLimitOrder};
 use prost::Message;
 
 pub fn create_stargate_msg(type_url: &str, value: Vec<u8>) -> StdResult<CosmosMsg<InjectiveMsgWrapper>> {
@@ -21,20 +19,19 @@ pub fn create_spot_limit_order(
     subaccount_id: &str,
     market: &SpotMarket,
 ) -> MsgCreateSpotLimitOrder {
+    let order = SpotOrder::new(
+        price,
+        quantity,
+        order_type,
+        &market.market_id,
+        SubaccountId::unchecked(subaccount_id),
+        Some(Addr::unchecked(sender)),
+        None,
+    );
+
     MsgCreateSpotLimitOrder {
         sender: sender.to_string(),
-        order: Some(SpotOrder {
-            market_id: market.market_id.as_str().into(),
-            order_info: Some(OrderInfo {
-                subaccount_id: subaccount_id.to_string(),
-                fee_recipient: sender.to_string(),
-                price: price.to_string(),
-                quantity: quantity.to_string(),
-                cid: "".to_string(),
-            }),
-            order_type: order_type as i32,
-            trigger_price: "".to_string(),
-        }),
+        order: Some(order.into()),
     }
 }
 
@@ -47,23 +44,22 @@ pub fn create_derivative_limit_order(
     subaccount_id: &str,
     market: &FullDerivativeMarket,
 ) -> MsgCreateDerivativeLimitOrder {
-    let market_id = market.market.as_ref().unwrap().market_id.as_str().to_string();
+    let market_id = market.market.as_ref().unwrap().market_id.clone();
+
+    let order = DerivativeOrder::new(
+        price,
+        quantity,
+        margin,
+        order_type,
+        market_id,
+        SubaccountId::unchecked(subaccount_id),
+        Some(Addr::unchecked(sender)),
+        None,
+    );
 
     MsgCreateDerivativeLimitOrder {
         sender: sender.to_string(),
-        order: Some(DerivativeOrder {
-            market_id,
-            order_info: Some(OrderInfo {
-                subaccount_id: subaccount_id.to_string(),
-                fee_recipient: sender.to_string(),
-                price: price.to_string(),
-                quantity: quantity.to_string(),
-                cid: "".to_string(),
-            }),
-            order_type: order_type as i32,
-            margin: margin.to_string(),
-            trigger_price: "".to_string(),
-        }),
+        order: Some(order.into()),
     }
 }
 
@@ -1,5 +1,5 @@
-use cosmwasm_std::{AnyMsg, CosmosMsg, StdResult};
-use injective_cosmwasm::{InjectiveMsgWrapper, OrderType, SpotMarket};
+use cosmwasm_std::{Addr, AnyMsg, CosmosMsg, StdResult};
+use injective_cosmwasm::{InjectiveMsgWrapper, OrderType, SpotMarket, SpotOrder, SubaccountId};
 use injective_math::{round_to_min_tick, round_to_nearest_tick, FPDecimal};
 use injective_std::types::injective::exchange::v1beta1 as Exchange;
 use prost::Message;
@@ -35,20 +35,20 @@ fn create_spot_market_order(
 ) -> Exchange::MsgCreateSpotMarketOrder {
     let rounded_quantity = round_to_min_tick(quantity, market.min_quantity_tick_size);
     let rounded_price = round_to_nearest_tick(price, market.min_price_tick_size);
+    let fee_recipient = (!fee_recipient.is_empty()).then(|| Addr::unchecked(fee_recipient));
+
+    let order = SpotOrder::new(
+        rounded_price,
+        rounded_quantity,
+        order_type,
+        &market.market_id,
+        SubaccountId::unchecked(subaccount_id),
+        fee_recipient,
+        None,
+    );
 
     Exchange::MsgCreateSpotMarketOrder {
         sender: sender.to_string(),
-        order: Some(Exchange::SpotOrder {
-            market_id: market.market_id.as_str().into(),
-            order_info: Some(Exchange::OrderInfo {
-                subaccount_id: subaccount_id.to_string(),
-                fee_recipient: fee_recipient.to_string(),
-                price: rounded_price.to_string(),
-                quantity: rounded_quantity.to_string(),
-                cid: "".to_string(),
-            }),
-            order_type: order_type as i32,
-            trigger_price: "".to_string(),
-        }),
+        order: Some(order.into()),
     }
 }
@@ -13,7 +13,7 @@ use injective_test_tube::{
     },
     Account, Exchange, Module, Wasm,
 };
-use injective_testing::utils::{human_to_dec, human_to_proto, scale_price_quantity_spot_market, str_coin};
+use injective_testing::utils::{human_to_dec, human_to_proto, str_coin};
 
 #[test]
 #[cfg_attr(not(feature = "integration"), ignore)]
@@ -113,7 +113,8 @@ fn test_query_trader_transient_spot_orders() {
 
     add_spot_initial_liquidity(&env.app, market_id.clone());
 
-    let (scale_price, scale_quantity) = scale_price_quantity_spot_market("9.8", "1", &BASE_DECIMALS, &QUOTE_DECIMALS);
+    let scale_price = human_to_dec("9.8", QUOTE_DECIMALS - BASE_DECIMALS);
+    let scale_quantity = human_to_dec("1", BASE_DECIMALS);
 
     let res = wasm
         .execute(
@@ -152,7 +153,8 @@ fn test_query_trader_spot_market_order() {
     execute_all_authorizations(&env.app, &env.users[0].account, env.contract_address.clone());
     add_spot_initial_liquidity(&env.app, market_id.clone());
 
-    let (scale_price, scale_quantity) = scale_price_quantity_spot_market("9.8", "1", &BASE_DECIMALS, &QUOTE_DECIMALS);
+    let scale_price = human_to_dec("9.8", QUOTE_DECIMALS - BASE_DECIMALS);
+    let scale_quantity = human_to_dec("1", BASE_DECIMALS);
 
     wasm.execute(
         &env.contract_address,
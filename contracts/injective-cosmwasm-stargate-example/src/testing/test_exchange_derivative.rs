@@ -240,6 +240,7 @@ fn test_query_trader_derivative_orders() {
         fillable: FPDecimal::must_from_str("1"),
         isBuy: false,
         order_hash: "".to_string(),
+        cid: None,
     };
     assert_eq!(orders[0].price, expected_order.price);
     assert_eq!(orders[0].quantity, expected_order.quantity);
@@ -366,7 +367,9 @@ fn test_query_trader_transient_derivative_orders() {
         margin,
     );
 
-    let (scale_price, scale_quantity, scaled_margin) = scale_price_quantity_perp_market("9.7", "0.1", "0.5", &QUOTE_DECIMALS);
+    let scale_price = human_to_dec("9.7", QUOTE_DECIMALS);
+    let scale_quantity = FPDecimal::must_from_str("0.1");
+    let scaled_margin = human_to_dec("0.485", QUOTE_DECIMALS);
     let res = wasm
         .execute(
             &env.contract_address,
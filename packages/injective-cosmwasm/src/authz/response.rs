@@ -1,11 +1,20 @@
+use cosmwasm_std::{from_json, StdResult};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::authz::types::{Authorization, PageRequest};
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct Grant {
-    authorization: String,
-    expiration: u64,
+    pub authorization: String,
+    pub expiration: u64,
+}
+
+impl Grant {
+    pub fn decode_authorization(&self) -> StdResult<Authorization> {
+        from_json(self.authorization.as_bytes())
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -17,6 +26,12 @@ pub struct GrantAuthorization {
     pub expiration: u64,
 }
 
+impl GrantAuthorization {
+    pub fn decode_authorization(&self) -> StdResult<Authorization> {
+        from_json(self.authorization.as_bytes())
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct PageResponse {
@@ -24,6 +39,16 @@ pub struct PageResponse {
     pub total: Option<u64>,
 }
 
+impl PageResponse {
+    /// Request for the following page, or None if this was the last one
+    pub fn next_page(&self, limit: Option<u64>) -> Option<PageRequest> {
+        match &self.next_key {
+            Some(key) if !key.is_empty() => Some(PageRequest::with_key(key.to_owned(), limit)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct GrantsResponse {
     pub grants: Option<Vec<Grant>>,
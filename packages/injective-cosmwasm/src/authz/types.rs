@@ -0,0 +1,236 @@
+use cosmwasm_std::Coin;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::types::{MarketId, SubaccountId};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PageRequest {
+    /// next_key returned by the previous page, mutually exclusive with offset
+    pub key: Option<Vec<u8>>,
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub count_total: bool,
+    #[serde(default)]
+    pub reverse: bool,
+}
+
+impl PageRequest {
+    pub fn with_limit(limit: u64) -> Self {
+        PageRequest {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_key(key: Vec<u8>, limit: Option<u64>) -> Self {
+        PageRequest {
+            key: Some(key),
+            limit,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ExchangeAuthz {
+    pub subaccount_id: SubaccountId,
+    #[serde(default)]
+    pub market_ids: Vec<MarketId>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchUpdateOrdersAuthz {
+    pub subaccount_id: SubaccountId,
+    #[serde(default)]
+    pub spot_markets: Vec<MarketId>,
+    #[serde(default)]
+    pub derivative_markets: Vec<MarketId>,
+}
+
+/// Authorization of a grant, decoded from its JSON `Any` representation which carries the type url in `@type`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "@type")]
+pub enum Authorization {
+    #[serde(rename = "/cosmos.authz.v1beta1.GenericAuthorization")]
+    Generic { msg: String },
+    #[serde(rename = "/cosmos.bank.v1beta1.SendAuthorization")]
+    Send {
+        spend_limit: Vec<Coin>,
+        #[serde(default)]
+        allow_list: Vec<String>,
+    },
+    #[serde(rename = "/injective.exchange.v1beta1.CreateSpotLimitOrderAuthz")]
+    CreateSpotLimitOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateSpotMarketOrderAuthz")]
+    CreateSpotMarketOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCreateSpotLimitOrdersAuthz")]
+    BatchCreateSpotLimitOrders(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CancelSpotOrderAuthz")]
+    CancelSpotOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCancelSpotOrdersAuthz")]
+    BatchCancelSpotOrders(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateDerivativeLimitOrderAuthz")]
+    CreateDerivativeLimitOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateDerivativeMarketOrderAuthz")]
+    CreateDerivativeMarketOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCreateDerivativeLimitOrdersAuthz")]
+    BatchCreateDerivativeLimitOrders(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CancelDerivativeOrderAuthz")]
+    CancelDerivativeOrder(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCancelDerivativeOrdersAuthz")]
+    BatchCancelDerivativeOrders(ExchangeAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchUpdateOrdersAuthz")]
+    BatchUpdateOrders(BatchUpdateOrdersAuthz),
+    #[serde(other)]
+    Unknown,
+}
+
+impl Authorization {
+    /// Type url of the message this authorization allows the grantee to execute
+    pub fn msg_type_url(&self) -> Option<&str> {
+        let msg_type_url = match self {
+            Authorization::Generic { msg } => msg.as_str(),
+            Authorization::Send { .. } => "/cosmos.bank.v1beta1.MsgSend",
+            Authorization::CreateSpotLimitOrder(_) => "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder",
+            Authorization::CreateSpotMarketOrder(_) => "/injective.exchange.v1beta1.MsgCreateSpotMarketOrder",
+            Authorization::BatchCreateSpotLimitOrders(_) => "/injective.exchange.v1beta1.MsgBatchCreateSpotLimitOrders",
+            Authorization::CancelSpotOrder(_) => "/injective.exchange.v1beta1.MsgCancelSpotOrder",
+            Authorization::BatchCancelSpotOrders(_) => "/injective.exchange.v1beta1.MsgBatchCancelSpotOrders",
+            Authorization::CreateDerivativeLimitOrder(_) => "/injective.exchange.v1beta1.MsgCreateDerivativeLimitOrder",
+            Authorization::CreateDerivativeMarketOrder(_) => "/injective.exchange.v1beta1.MsgCreateDerivativeMarketOrder",
+            Authorization::BatchCreateDerivativeLimitOrders(_) => "/injective.exchange.v1beta1.MsgBatchCreateDerivativeLimitOrders",
+            Authorization::CancelDerivativeOrder(_) => "/injective.exchange.v1beta1.MsgCancelDerivativeOrder",
+            Authorization::BatchCancelDerivativeOrders(_) => "/injective.exchange.v1beta1.MsgBatchCancelDerivativeOrders",
+            Authorization::BatchUpdateOrders(_) => "/injective.exchange.v1beta1.MsgBatchUpdateOrders",
+            Authorization::Unknown => return None,
+        };
+
+        Some(msg_type_url)
+    }
+
+    pub fn allows_msg(&self, msg_type_url: &str) -> bool {
+        self.msg_type_url() == Some(msg_type_url)
+    }
+
+    /// Returns false for exchange authorizations which are restricted to a different subaccount or set of markets
+    pub fn allows_market(&self, subaccount_id: &SubaccountId, market_id: &MarketId) -> bool {
+        match self {
+            Authorization::Generic { .. } => true,
+            Authorization::Send { .. } | Authorization::Unknown => false,
+            Authorization::BatchUpdateOrders(authz) => {
+                &authz.subaccount_id == subaccount_id && (authz.spot_markets.contains(market_id) || authz.derivative_markets.contains(market_id))
+            }
+            Authorization::CreateSpotLimitOrder(authz)
+            | Authorization::CreateSpotMarketOrder(authz)
+            | Authorization::BatchCreateSpotLimitOrders(authz)
+            | Authorization::CancelSpotOrder(authz)
+            | Authorization::BatchCancelSpotOrders(authz)
+            | Authorization::CreateDerivativeLimitOrder(authz)
+            | Authorization::CreateDerivativeMarketOrder(authz)
+            | Authorization::BatchCreateDerivativeLimitOrders(authz)
+            | Authorization::CancelDerivativeOrder(authz)
+            | Authorization::BatchCancelDerivativeOrders(authz) => &authz.subaccount_id == subaccount_id && authz.market_ids.contains(market_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Coin};
+
+    use super::*;
+    use crate::authz::response::GrantAuthorization;
+    use crate::test_helpers::testing_helpers::{inj_mock_deps, TEST_MARKET_ID_1};
+    use crate::{create_granter_grants_handler, InjectiveQuerier};
+
+    const SUBACCOUNT_ID: &str = "0x1a0a6a2a5b8f2bbd5a7e7c1cf37b7bcbd1fe44d6000000000000000000000000";
+
+    #[test]
+    fn decodes_generic_and_send_authorizations() {
+        let generic: Authorization =
+            from_json(r#"{"@type":"/cosmos.authz.v1beta1.GenericAuthorization","msg":"/cosmos.bank.v1beta1.MsgSend"}"#).unwrap();
+        assert_eq!(
+            generic,
+            Authorization::Generic {
+                msg: "/cosmos.bank.v1beta1.MsgSend".to_string()
+            }
+        );
+        assert!(generic.allows_msg("/cosmos.bank.v1beta1.MsgSend"));
+
+        let send: Authorization =
+            from_json(r#"{"@type":"/cosmos.bank.v1beta1.SendAuthorization","spend_limit":[{"denom":"inj","amount":"1000"}]}"#).unwrap();
+        assert_eq!(
+            send,
+            Authorization::Send {
+                spend_limit: vec![Coin::new(1000u128, "inj")],
+                allow_list: vec![],
+            }
+        );
+        assert!(send.allows_msg("/cosmos.bank.v1beta1.MsgSend"));
+    }
+
+    #[test]
+    fn decodes_exchange_authorizations() {
+        let json = format!(
+            r#"{{"@type":"/injective.exchange.v1beta1.CreateSpotMarketOrderAuthz","subaccount_id":"{SUBACCOUNT_ID}","market_ids":["{TEST_MARKET_ID_1}"]}}"#
+        );
+        let authorization: Authorization = from_json(json).unwrap();
+
+        assert!(authorization.allows_msg("/injective.exchange.v1beta1.MsgCreateSpotMarketOrder"));
+        assert!(!authorization.allows_msg("/injective.exchange.v1beta1.MsgCreateSpotLimitOrder"));
+        assert!(authorization.allows_market(&SubaccountId::unchecked(SUBACCOUNT_ID), &MarketId::unchecked(TEST_MARKET_ID_1)));
+        assert!(!authorization.allows_market(
+            &SubaccountId::unchecked(SUBACCOUNT_ID),
+            &MarketId::unchecked("0xa815458b073ea303494e0c87f532483834f85622e1db1ad08e4ece2d360b248d")
+        ));
+
+        let json = format!(
+            r#"{{"@type":"/injective.exchange.v1beta1.BatchUpdateOrdersAuthz","subaccount_id":"{SUBACCOUNT_ID}","derivative_markets":["{TEST_MARKET_ID_1}"]}}"#
+        );
+        let authorization: Authorization = from_json(json).unwrap();
+        assert!(authorization.allows_msg("/injective.exchange.v1beta1.MsgBatchUpdateOrders"));
+        assert!(authorization.allows_market(&SubaccountId::unchecked(SUBACCOUNT_ID), &MarketId::unchecked(TEST_MARKET_ID_1)));
+    }
+
+    #[test]
+    fn unknown_authorization_allows_nothing() {
+        let authorization: Authorization =
+            from_json(r#"{"@type":"/cosmos.staking.v1beta1.StakeAuthorization","max_tokens":null,"authorization_type":1}"#).unwrap();
+
+        assert_eq!(authorization, Authorization::Unknown);
+        assert_eq!(authorization.msg_type_url(), None);
+    }
+
+    #[test]
+    fn queries_granter_grants_page_by_page() {
+        let grants: Vec<GrantAuthorization> = (0..5)
+            .map(|i| GrantAuthorization {
+                granter: "inj1granter".to_string(),
+                grantee: format!("inj1grantee{i}"),
+                authorization: r#"{"@type":"/cosmos.authz.v1beta1.GenericAuthorization","msg":"/cosmos.bank.v1beta1.MsgSend"}"#.to_string(),
+                expiration: 1700000000,
+            })
+            .collect();
+        let deps = inj_mock_deps(|querier| querier.granter_grants_response_handler = create_granter_grants_handler(grants));
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let mut grantees = vec![];
+        let mut pagination = Some(PageRequest::with_limit(2));
+        while let Some(page) = pagination {
+            let response = querier.query_granter_grants("inj1granter", Some(page)).unwrap();
+            for grant in response.grants.unwrap() {
+                assert!(grant.decode_authorization().unwrap().allows_msg("/cosmos.bank.v1beta1.MsgSend"));
+                grantees.push(grant.grantee);
+            }
+            pagination = response.pagination.and_then(|p| p.next_page(Some(2)));
+        }
+
+        assert_eq!(grantees, (0..5).map(|i| format!("inj1grantee{i}")).collect::<Vec<_>>());
+    }
+}
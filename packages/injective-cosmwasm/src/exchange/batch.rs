@@ -0,0 +1,360 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, AnyMsg, CosmosMsg, StdError};
+use injective_std::types::injective::exchange::v1beta1 as exchange;
+use prost::Message;
+use thiserror::Error;
+
+use crate::exchange::{
+    derivative::DerivativeOrder,
+    order::OrderData,
+    spot::SpotOrder,
+    types::{MarketId, Params, SubaccountId},
+};
+use crate::msg::{create_batch_update_orders_msg, InjectiveMsgWrapper};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum BatchUpdateOrdersError {
+    #[error("Order in market {} belongs to subaccount {} instead of the batch subaccount {}", market_id.as_str(), actual.as_str(), expected.as_str())]
+    SubaccountMismatch {
+        market_id: MarketId,
+        expected: SubaccountId,
+        actual: SubaccountId,
+    },
+
+    #[error("Market {} is cancelled entirely and by order hash in the same batch", .0.as_str())]
+    ConflictingCancels(MarketId),
+
+    #[error("Batch creates {count} {} orders in market {}, max is {max}", if *is_buy { "buy" } else { "sell" }, market_id.as_str())]
+    TooManyOrders {
+        market_id: MarketId,
+        is_buy: bool,
+        count: u32,
+        max: u32,
+    },
+}
+
+impl From<BatchUpdateOrdersError> for StdError {
+    fn from(err: BatchUpdateOrdersError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+/// Collects order creations and cancellations for a single subaccount into one `BatchUpdateOrders` message.
+/// Duplicate cancellations are dropped, the remaining checks run on `build`.
+#[derive(Clone, Debug)]
+pub struct BatchUpdateOrdersBuilder {
+    sender: Addr,
+    subaccount_id: SubaccountId,
+    spot_market_ids_to_cancel_all: Vec<MarketId>,
+    derivative_market_ids_to_cancel_all: Vec<MarketId>,
+    spot_orders_to_cancel: Vec<OrderData>,
+    derivative_orders_to_cancel: Vec<OrderData>,
+    spot_orders_to_create: Vec<SpotOrder>,
+    derivative_orders_to_create: Vec<DerivativeOrder>,
+    max_derivative_order_side_count: Option<u32>,
+}
+
+impl BatchUpdateOrdersBuilder {
+    pub fn new(sender: Addr, subaccount_id: SubaccountId) -> Self {
+        BatchUpdateOrdersBuilder {
+            sender,
+            subaccount_id,
+            spot_market_ids_to_cancel_all: vec![],
+            derivative_market_ids_to_cancel_all: vec![],
+            spot_orders_to_cancel: vec![],
+            derivative_orders_to_cancel: vec![],
+            spot_orders_to_create: vec![],
+            derivative_orders_to_create: vec![],
+            max_derivative_order_side_count: None,
+        }
+    }
+
+    /// Limits the derivative orders created per market and side to the exchange's `max_derivative_order_side_count`.
+    /// Only orders within this batch are counted, resting orders are not known to the builder.
+    pub fn with_params(mut self, params: &Params) -> Self {
+        self.max_derivative_order_side_count = Some(params.max_derivative_order_side_count);
+        self
+    }
+
+    pub fn cancel_all_spot_orders(mut self, market_id: MarketId) -> Self {
+        push_unique(&mut self.spot_market_ids_to_cancel_all, market_id);
+        self
+    }
+
+    pub fn cancel_all_derivative_orders(mut self, market_id: MarketId) -> Self {
+        push_unique(&mut self.derivative_market_ids_to_cancel_all, market_id);
+        self
+    }
+
+    pub fn cancel_spot_order(mut self, market_id: MarketId, order_hash: impl Into<String>) -> Self {
+        let order = self.order_data(market_id, order_hash.into());
+        push_unique(&mut self.spot_orders_to_cancel, order);
+        self
+    }
+
+    pub fn cancel_derivative_order(mut self, market_id: MarketId, order_hash: impl Into<String>) -> Self {
+        let order = self.order_data(market_id, order_hash.into());
+        push_unique(&mut self.derivative_orders_to_cancel, order);
+        self
+    }
+
+    pub fn create_spot_order(mut self, order: SpotOrder) -> Self {
+        self.spot_orders_to_create.push(order);
+        self
+    }
+
+    pub fn create_derivative_order(mut self, order: DerivativeOrder) -> Self {
+        self.derivative_orders_to_create.push(order);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spot_market_ids_to_cancel_all.is_empty()
+            && self.derivative_market_ids_to_cancel_all.is_empty()
+            && self.spot_orders_to_cancel.is_empty()
+            && self.derivative_orders_to_cancel.is_empty()
+            && self.spot_orders_to_create.is_empty()
+            && self.derivative_orders_to_create.is_empty()
+    }
+
+    /// Builds the custom `InjectiveMsg::BatchUpdateOrders` message
+    pub fn build(self) -> Result<CosmosMsg<InjectiveMsgWrapper>, BatchUpdateOrdersError> {
+        self.validate()?;
+
+        Ok(create_batch_update_orders_msg(
+            self.sender,
+            Some(self.subaccount_id),
+            self.spot_market_ids_to_cancel_all,
+            self.derivative_market_ids_to_cancel_all,
+            self.spot_orders_to_cancel,
+            self.derivative_orders_to_cancel,
+            self.spot_orders_to_create,
+            self.derivative_orders_to_create,
+        ))
+    }
+
+    /// Builds the protobuf `MsgBatchUpdateOrders` for contracts dispatching stargate messages
+    pub fn build_proto(self) -> Result<exchange::MsgBatchUpdateOrders, BatchUpdateOrdersError> {
+        self.validate()?;
+
+        Ok(exchange::MsgBatchUpdateOrders {
+            sender: self.sender.into_string(),
+            subaccount_id: self.subaccount_id.into(),
+            spot_market_ids_to_cancel_all: self.spot_market_ids_to_cancel_all.into_iter().map(Into::into).collect(),
+            derivative_market_ids_to_cancel_all: self.derivative_market_ids_to_cancel_all.into_iter().map(Into::into).collect(),
+            spot_orders_to_cancel: self.spot_orders_to_cancel.into_iter().map(Into::into).collect(),
+            derivative_orders_to_cancel: self.derivative_orders_to_cancel.into_iter().map(Into::into).collect(),
+            spot_orders_to_create: self.spot_orders_to_create.into_iter().map(Into::into).collect(),
+            derivative_orders_to_create: self.derivative_orders_to_create.into_iter().map(Into::into).collect(),
+            binary_options_orders_to_cancel: vec![],
+            binary_options_market_ids_to_cancel_all: vec![],
+            binary_options_orders_to_create: vec![],
+        })
+    }
+
+    pub fn build_stargate(self) -> Result<CosmosMsg<InjectiveMsgWrapper>, BatchUpdateOrdersError> {
+        let msg = self.build_proto()?;
+
+        Ok(CosmosMsg::Any(AnyMsg {
+            type_url: exchange::MsgBatchUpdateOrders::TYPE_URL.to_string(),
+            value: msg.encode_to_vec().into(),
+        }))
+    }
+
+    fn order_data(&self, market_id: MarketId, order_hash: String) -> OrderData {
+        OrderData {
+            market_id,
+            subaccount_id: self.subaccount_id.clone(),
+            order_hash,
+        }
+    }
+
+    fn validate(&self) -> Result<(), BatchUpdateOrdersError> {
+        check_conflicting_cancels(&self.spot_market_ids_to_cancel_all, &self.spot_orders_to_cancel)?;
+        check_conflicting_cancels(&self.derivative_market_ids_to_cancel_all, &self.derivative_orders_to_cancel)?;
+
+        let spot_orders = self
+            .spot_orders_to_create
+            .iter()
+            .map(|order| (&order.market_id, &order.order_info.subaccount_id));
+        let derivative_orders = self
+            .derivative_orders_to_create
+            .iter()
+            .map(|order| (&order.market_id, &order.order_info.subaccount_id));
+        for (market_id, subaccount_id) in spot_orders.chain(derivative_orders) {
+            if subaccount_id != &self.subaccount_id {
+                return Err(BatchUpdateOrdersError::SubaccountMismatch {
+                    market_id: market_id.clone(),
+                    expected: self.subaccount_id.clone(),
+                    actual: subaccount_id.clone(),
+                });
+            }
+        }
+
+        if let Some(max) = self.max_derivative_order_side_count {
+            let mut counts: BTreeMap<(&str, bool), u32> = BTreeMap::new();
+            for order in &self.derivative_orders_to_create {
+                *counts.entry((order.market_id.as_str(), order.order_type.is_buy())).or_default() += 1;
+            }
+
+            if let Some(((market_id, is_buy), count)) = counts.into_iter().find(|(_, count)| *count > max) {
+                return Err(BatchUpdateOrdersError::TooManyOrders {
+                    market_id: MarketId::unchecked(market_id),
+                    is_buy,
+                    count,
+                    max,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn push_unique<T: PartialEq>(items: &mut Vec<T>, item: T) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+fn check_conflicting_cancels(market_ids_to_cancel_all: &[MarketId], orders_to_cancel: &[OrderData]) -> Result<(), BatchUpdateOrdersError> {
+    match orders_to_cancel.iter().find(|order| market_ids_to_cancel_all.contains(&order.market_id)) {
+        Some(order) => Err(BatchUpdateOrdersError::ConflictingCancels(order.market_id.clone())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::{display::ToProto, FPDecimal};
+
+    use super::*;
+    use crate::exchange::order::OrderType;
+    use crate::msg::InjectiveMsg;
+    use crate::test_helpers::testing_helpers::{inj_mock_deps, TEST_MARKET_ID_1, TEST_MARKET_ID_2};
+    use crate::InjectiveQuerier;
+
+    const SUBACCOUNT_ID: &str = "0x427aee334987c52fa7b567b2662bdbb68614e48c000000000000000000000001";
+    const OTHER_SUBACCOUNT_ID: &str = "0x427aee334987c52fa7b567b2662bdbb68614e48c000000000000000000000002";
+
+    fn builder() -> BatchUpdateOrdersBuilder {
+        BatchUpdateOrdersBuilder::new(Addr::unchecked("inj1contract"), SubaccountId::unchecked(SUBACCOUNT_ID))
+    }
+
+    fn derivative_order(market_id: &str, order_type: OrderType, subaccount_id: &str) -> DerivativeOrder {
+        DerivativeOrder::new(
+            FPDecimal::must_from_str("10"),
+            FPDecimal::ONE,
+            FPDecimal::must_from_str("10"),
+            order_type,
+            MarketId::unchecked(market_id),
+            SubaccountId::unchecked(subaccount_id),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn builds_batch_with_deduplicated_cancels() {
+        let spot_order = SpotOrder::new(
+            FPDecimal::must_from_str("10"),
+            FPDecimal::ONE,
+            OrderType::Sell,
+            &MarketId::unchecked(TEST_MARKET_ID_1),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        );
+        let msg = builder()
+            .cancel_all_spot_orders(MarketId::unchecked(TEST_MARKET_ID_1))
+            .cancel_all_spot_orders(MarketId::unchecked(TEST_MARKET_ID_1))
+            .cancel_derivative_order(MarketId::unchecked(TEST_MARKET_ID_2), "0x01")
+            .cancel_derivative_order(MarketId::unchecked(TEST_MARKET_ID_2), "0x01")
+            .create_spot_order(spot_order)
+            .create_derivative_order(derivative_order(TEST_MARKET_ID_2, OrderType::Buy, SUBACCOUNT_ID))
+            .build()
+            .unwrap();
+
+        let CosmosMsg::Custom(InjectiveMsgWrapper {
+            msg_data:
+                InjectiveMsg::BatchUpdateOrders {
+                    subaccount_id,
+                    spot_market_ids_to_cancel_all,
+                    derivative_orders_to_cancel,
+                    spot_orders_to_create,
+                    derivative_orders_to_create,
+                    ..
+                },
+            ..
+        }) = msg
+        else {
+            panic!("expected a batch update orders message");
+        };
+        assert_eq!(subaccount_id, Some(SubaccountId::unchecked(SUBACCOUNT_ID)));
+        assert_eq!(spot_market_ids_to_cancel_all, vec![MarketId::unchecked(TEST_MARKET_ID_1)]);
+        assert_eq!(derivative_orders_to_cancel.len(), 1);
+        assert_eq!(spot_orders_to_create.len(), 1);
+        assert_eq!(derivative_orders_to_create.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_batches() {
+        let err = builder()
+            .cancel_all_derivative_orders(MarketId::unchecked(TEST_MARKET_ID_2))
+            .cancel_derivative_order(MarketId::unchecked(TEST_MARKET_ID_2), "0x01")
+            .build()
+            .unwrap_err();
+        assert_eq!(err, BatchUpdateOrdersError::ConflictingCancels(MarketId::unchecked(TEST_MARKET_ID_2)));
+
+        let err = builder()
+            .create_derivative_order(derivative_order(TEST_MARKET_ID_2, OrderType::Buy, OTHER_SUBACCOUNT_ID))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, BatchUpdateOrdersError::SubaccountMismatch { .. }));
+
+        let mut params_builder = builder();
+        for _ in 0..3 {
+            params_builder = params_builder.create_derivative_order(derivative_order(TEST_MARKET_ID_2, OrderType::Sell, SUBACCOUNT_ID));
+        }
+        let deps = inj_mock_deps(|_| {});
+        let mut params = InjectiveQuerier::new(&deps.as_ref().querier)
+            .query_exchange_params()
+            .unwrap()
+            .params
+            .unwrap();
+        params.max_derivative_order_side_count = 2;
+        let err = params_builder.with_params(&params).build().unwrap_err();
+        assert_eq!(
+            err,
+            BatchUpdateOrdersError::TooManyOrders {
+                market_id: MarketId::unchecked(TEST_MARKET_ID_2),
+                is_buy: false,
+                count: 3,
+                max: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn builds_stargate_message() {
+        let msg = builder()
+            .cancel_spot_order(MarketId::unchecked(TEST_MARKET_ID_1), "0x01")
+            .create_derivative_order(derivative_order(TEST_MARKET_ID_2, OrderType::Buy, SUBACCOUNT_ID))
+            .build_stargate()
+            .unwrap();
+
+        let CosmosMsg::Any(AnyMsg { type_url, value }) = msg else {
+            panic!("expected a stargate message");
+        };
+        assert_eq!(type_url, "/injective.exchange.v1beta1.MsgBatchUpdateOrders");
+
+        let decoded = exchange::MsgBatchUpdateOrders::decode(value.as_slice()).unwrap();
+        assert_eq!(decoded.subaccount_id, SUBACCOUNT_ID);
+        assert_eq!(decoded.spot_orders_to_cancel[0].order_hash, "0x01");
+        assert_eq!(
+            decoded.derivative_orders_to_create[0].margin,
+            FPDecimal::must_from_str("10").to_proto_string()
+        );
+    }
+}
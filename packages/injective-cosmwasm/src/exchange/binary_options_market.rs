@@ -0,0 +1,182 @@
+use crate::exchange::types::MarketId;
+use crate::oracle::types::OracleType;
+use cosmwasm_std::StdResult;
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::market::{hash_market_id, GenericMarket, MarketStatus};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BinaryOptionsMarket {
+    pub ticker: String,
+    pub oracle_symbol: String,
+    pub oracle_provider: String,
+    #[serde(default)]
+    pub oracle_type: OracleType,
+    #[serde(default)]
+    pub oracle_scale_factor: u32,
+    pub expiration_timestamp: i64,
+    pub settlement_timestamp: i64,
+    #[serde(default)]
+    pub admin: String,
+    pub quote_denom: String,
+    pub market_id: MarketId,
+    pub maker_fee_rate: FPDecimal,
+    pub taker_fee_rate: FPDecimal,
+    pub relayer_fee_share_rate: FPDecimal,
+    #[serde(default)]
+    pub status: MarketStatus,
+    pub min_price_tick_size: FPDecimal,
+    pub min_quantity_tick_size: FPDecimal,
+    #[serde(default)]
+    pub settlement_price: Option<FPDecimal>,
+    pub min_notional: FPDecimal,
+}
+
+impl BinaryOptionsMarket {
+    /// Returns true once trading has stopped, i.e. the block time has reached the expiration timestamp
+    pub fn is_expired(&self, block_timestamp: i64) -> bool {
+        block_timestamp >= self.expiration_timestamp
+    }
+
+    /// Returns true once the market can be (or has been) settled at the settlement timestamp
+    pub fn is_settlement_due(&self, block_timestamp: i64) -> bool {
+        block_timestamp >= self.settlement_timestamp
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settlement_price.is_some() && self.status == MarketStatus::Demolished
+    }
+}
+
+impl GenericMarket for BinaryOptionsMarket {
+    fn get_ticker(&self) -> &str {
+        &self.ticker
+    }
+
+    fn get_quote_denom(&self) -> &str {
+        &self.quote_denom
+    }
+
+    fn get_maker_fee_rate(&self) -> FPDecimal {
+        self.maker_fee_rate
+    }
+
+    fn get_taker_fee_rate(&self) -> FPDecimal {
+        self.taker_fee_rate
+    }
+
+    fn get_market_id(&self) -> &MarketId {
+        &self.market_id
+    }
+
+    fn get_status(&self) -> MarketStatus {
+        self.status
+    }
+
+    fn get_min_price_tick_size(&self) -> FPDecimal {
+        self.min_price_tick_size
+    }
+
+    fn min_quantity_tick_size(&self) -> FPDecimal {
+        self.min_quantity_tick_size
+    }
+
+    fn get_min_notional(&self) -> FPDecimal {
+        self.min_notional
+    }
+
+    fn get_relayer_fee_share_rate(&self) -> Option<FPDecimal> {
+        Some(self.relayer_fee_share_rate)
+    }
+}
+
+pub fn calculate_binary_options_market_id(
+    ticker: &str,
+    quote_denom: &str,
+    oracle_symbol: &str,
+    oracle_provider: &str,
+    oracle_type: OracleType,
+) -> StdResult<MarketId> {
+    hash_market_id(&[oracle_type.as_str_name(), ticker, quote_denom, oracle_symbol, oracle_provider])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKET_JSON: &str = r#"{
+        "ticker": "BTC>100k",
+        "oracle_symbol": "BTC",
+        "oracle_provider": "frontrunner",
+        "oracle_type": 11,
+        "oracle_scale_factor": 6,
+        "expiration_timestamp": 1700000000,
+        "settlement_timestamp": 1700003600,
+        "admin": "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv",
+        "quote_denom": "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7",
+        "market_id": "0xe2521e2367a5a3411365fdc75c0a07abd2ee8add32f91070750c9455a4bde41a",
+        "maker_fee_rate": "0.0005",
+        "taker_fee_rate": "0.001",
+        "relayer_fee_share_rate": "0.4",
+        "status": 1,
+        "min_price_tick_size": "10000",
+        "min_quantity_tick_size": "1",
+        "settlement_price": null,
+        "min_notional": "1000000"
+    }"#;
+
+    #[test]
+    fn binary_options_market_deserialization() {
+        let market: BinaryOptionsMarket = serde_json_wasm::from_str(MARKET_JSON).unwrap();
+
+        assert_eq!(market.get_ticker(), "BTC>100k");
+        assert_eq!(market.oracle_type, OracleType::Provider);
+        assert_eq!(market.get_status(), MarketStatus::Active);
+        assert_eq!(market.get_min_price_tick_size(), FPDecimal::must_from_str("10000"));
+        assert_eq!(market.settlement_price, None);
+        assert!(!market.is_settled());
+    }
+
+    #[test]
+    fn binary_options_market_expiry_and_settlement() {
+        let mut market: BinaryOptionsMarket = serde_json_wasm::from_str(MARKET_JSON).unwrap();
+
+        assert!(!market.is_expired(1699999999));
+        assert!(market.is_expired(1700000000));
+        assert!(!market.is_settlement_due(1700000000));
+        assert!(market.is_settlement_due(1700003600));
+
+        market.settlement_price = Some(FPDecimal::ONE);
+        market.status = MarketStatus::Demolished;
+        assert!(market.is_settled());
+    }
+
+    #[test]
+    fn test_binary_options_market_id() {
+        // same preimage order as the chain's NewBinaryOptionsMarketID: oracle type, ticker, quote denom, symbol, provider
+        let market: BinaryOptionsMarket = serde_json_wasm::from_str(MARKET_JSON).unwrap();
+        let result = calculate_binary_options_market_id(
+            &market.ticker,
+            &market.quote_denom,
+            &market.oracle_symbol,
+            &market.oracle_provider,
+            market.oracle_type,
+        )
+        .unwrap();
+
+        assert_eq!(result, market.market_id);
+        assert_ne!(
+            result,
+            calculate_binary_options_market_id(
+                &market.ticker,
+                &market.quote_denom,
+                &market.oracle_symbol,
+                &market.oracle_provider,
+                OracleType::Pyth
+            )
+            .unwrap()
+        );
+    }
+}
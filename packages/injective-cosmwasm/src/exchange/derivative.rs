@@ -1,10 +1,11 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, StdError, StdResult};
+use injective_std::types::injective::exchange::v1beta1 as exchange;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use injective_math::FPDecimal;
+use injective_math::{display::ToProto, FPDecimal};
 
-use crate::exchange::order::{GenericOrder, OrderInfo, OrderType};
+use crate::exchange::order::{dec_from_proto, optional_dec_from_proto, optional_dec_to_proto, GenericOrder, OrderInfo, OrderMask, OrderType};
 use crate::exchange::types::{MarketId, SubaccountId};
 use crate::ShortSubaccountId;
 
@@ -110,6 +111,25 @@ impl DerivativeOrder {
             trigger_price: None,
         }
     }
+
+    /// Creates a stop or take order, which rests until the mark price reaches `trigger_price`
+    pub fn new_conditional(
+        price: FPDecimal,
+        quantity: FPDecimal,
+        margin: FPDecimal,
+        trigger_price: FPDecimal,
+        order_type: OrderType,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        fee_recipient: Option<Addr>,
+        cid: Option<String>,
+    ) -> Self {
+        DerivativeOrder {
+            trigger_price: Some(trigger_price),
+            ..DerivativeOrder::new(price, quantity, margin, order_type, market_id, subaccount_id, fee_recipient, cid)
+        }
+    }
+
     pub fn is_reduce_only(&self) -> bool {
         self.margin.is_zero()
     }
@@ -167,6 +187,36 @@ impl GenericOrder for DerivativeOrder {
     }
 }
 
+impl From<DerivativeOrder> for exchange::DerivativeOrder {
+    fn from(order: DerivativeOrder) -> Self {
+        exchange::DerivativeOrder {
+            market_id: order.market_id.into(),
+            order_info: Some(order.order_info.into()),
+            order_type: order.order_type.into(),
+            margin: order.margin.to_proto_string(),
+            trigger_price: optional_dec_to_proto(order.trigger_price),
+        }
+    }
+}
+
+impl TryFrom<exchange::DerivativeOrder> for DerivativeOrder {
+    type Error = StdError;
+
+    fn try_from(order: exchange::DerivativeOrder) -> StdResult<Self> {
+        let order_info = order
+            .order_info
+            .ok_or_else(|| StdError::generic_err("Missing order info in derivative order"))?;
+
+        Ok(DerivativeOrder {
+            market_id: MarketId::new(order.market_id)?,
+            order_info: order_info.try_into()?,
+            order_type: order.order_type.try_into()?,
+            margin: dec_from_proto(&order.margin)?,
+            trigger_price: optional_dec_from_proto(&order.trigger_price)?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct ShortDerivativeOrder {
     pub market_id: MarketId,
@@ -351,6 +401,35 @@ impl DerivativeMarketOrder {
     }
 }
 
+/// Conditional order which has not been triggered yet
+#[allow(non_snake_case)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TrimmedDerivativeConditionalOrder {
+    pub price: FPDecimal,
+    pub quantity: FPDecimal,
+    #[serde(default)]
+    pub margin: FPDecimal,
+    pub triggerPrice: FPDecimal,
+    #[serde(default)]
+    pub isBuy: bool,
+    #[serde(default)]
+    pub isLimit: bool,
+    pub order_hash: String,
+    #[serde(default)]
+    pub cid: Option<String>,
+}
+
+impl TrimmedDerivativeConditionalOrder {
+    /// The trimmed order does not record whether it is a stop or a take order, so the mask matches either trigger direction
+    pub fn order_mask(&self) -> OrderMask {
+        OrderMask::conditional(None, self.isLimit)
+    }
+
+    pub fn is_reduce_only(&self) -> bool {
+        self.margin.is_zero()
+    }
+}
+
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TrimmedDerivativeLimitOrder {
@@ -362,6 +441,8 @@ pub struct TrimmedDerivativeLimitOrder {
     #[serde(default)]
     pub isBuy: bool,
     pub order_hash: String,
+    #[serde(default)]
+    pub cid: Option<String>,
 }
 
 impl GenericTrimmedOrder for TrimmedDerivativeLimitOrder {
@@ -384,4 +465,8 @@ impl GenericTrimmedOrder for TrimmedDerivativeLimitOrder {
     fn get_order_hash(&self) -> String {
         self.order_hash.to_owned()
     }
+
+    fn get_cid(&self) -> Option<&str> {
+        self.cid.as_deref().filter(|cid| !cid.is_empty())
+    }
 }
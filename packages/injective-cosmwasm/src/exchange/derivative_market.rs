@@ -1,10 +1,11 @@
 use crate::exchange::types::MarketId;
 use crate::oracle::types::OracleType;
+use cosmwasm_std::StdResult;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::market::{GenericMarket, MarketStatus};
+use super::market::{hash_market_id, GenericMarket, MarketStatus};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PerpetualMarketInfo {
@@ -68,6 +69,10 @@ pub struct DerivativeMarket {
     pub status: MarketStatus,
     pub min_price_tick_size: FPDecimal,
     pub min_quantity_tick_size: FPDecimal,
+    #[serde(default)]
+    pub min_notional: FPDecimal,
+    #[serde(default)]
+    pub relayer_fee_share_rate: Option<FPDecimal>,
 }
 
 impl GenericMarket for DerivativeMarket {
@@ -102,4 +107,66 @@ impl GenericMarket for DerivativeMarket {
     fn min_quantity_tick_size(&self) -> FPDecimal {
         self.min_quantity_tick_size
     }
+
+    fn get_min_notional(&self) -> FPDecimal {
+        self.min_notional
+    }
+
+    fn get_relayer_fee_share_rate(&self) -> Option<FPDecimal> {
+        self.relayer_fee_share_rate
+    }
+}
+
+pub fn calculate_perpetual_market_id(
+    ticker: &str,
+    quote_denom: &str,
+    oracle_base: &str,
+    oracle_quote: &str,
+    oracle_type: OracleType,
+) -> StdResult<MarketId> {
+    hash_market_id(&[oracle_type.as_str_name(), ticker, quote_denom, oracle_base, oracle_quote])
+}
+
+pub fn calculate_expiry_futures_market_id(
+    ticker: &str,
+    quote_denom: &str,
+    oracle_base: &str,
+    oracle_quote: &str,
+    oracle_type: OracleType,
+    expiry: i64,
+) -> StdResult<MarketId> {
+    hash_market_id(&[
+        oracle_type.as_str_name(),
+        ticker,
+        quote_denom,
+        oracle_base,
+        oracle_quote,
+        &expiry.to_string(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDT: &str = "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7";
+
+    #[test]
+    fn test_perpetual_market_id() {
+        // INJ/USDT PERP on mainnet
+        let result = calculate_perpetual_market_id("INJ/USDT PERP", USDT, "INJ", "USDT", OracleType::BandIBC).unwrap();
+
+        assert_eq!(result.as_str(), "0x9b9980167ecc3645ff1a5517886652d94a0825e54a77d2057cbbe3ebee015963");
+    }
+
+    #[test]
+    fn test_expiry_futures_market_id() {
+        let result = calculate_expiry_futures_market_id("INJ/USDT 24-MAR-2023", USDT, "INJ", "USDT", OracleType::BandIBC, 1679673600).unwrap();
+
+        assert_eq!(result.as_str(), "0x7c48a4dd92ee337d115baebfa62d1f438ad809c50e3748ba24155b8b23dcf088");
+        assert_ne!(
+            result,
+            calculate_expiry_futures_market_id("INJ/USDT 24-MAR-2023", USDT, "INJ", "USDT", OracleType::BandIBC, 1679673601).unwrap()
+        );
+    }
 }
@@ -0,0 +1,253 @@
+use cosmwasm_std::Addr;
+use injective_math::FPDecimal;
+
+use crate::exchange::{
+    market::GenericMarket,
+    response::{FeeDiscountAccountInfoResponse, QueryMarketAtomicExecutionFeeMultiplierResponse},
+    types::{FeeDiscountSchedule, MarketId, Params},
+};
+
+/// Expected fee of a trade, all amounts are denominated in the quote denom
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeFee {
+    /// Fee rate after discounts and multipliers, negative for maker rebates
+    pub fee_rate: FPDecimal,
+    /// Fee paid by the trader, zero when the trade earns a rebate.
+    /// Without a fee recipient the chain pays the relayer share to the trader, so it is deducted from the fee.
+    pub fee: FPDecimal,
+    /// Rebate paid to the trader for negative maker fees
+    pub rebate: FPDecimal,
+    /// Part of the fee paid out to the order's fee recipient, zero when the order has none
+    pub relayer_fee: FPDecimal,
+}
+
+impl TradeFee {
+    /// Part of the fee kept by the exchange
+    pub fn protocol_fee(&self) -> FPDecimal {
+        self.fee - self.relayer_fee
+    }
+}
+
+/// Estimates trading fees of a market from its fee rates, the trader's fee discount tier,
+/// the relayer fee share and, for atomic orders, the atomic execution fee multiplier.
+/// The relayer fee share is the market's own rate, or the exchange params default for markets without one.
+#[derive(Clone, Debug)]
+pub struct FeeCalculator {
+    market_id: MarketId,
+    quote_denom: String,
+    maker_fee_rate: FPDecimal,
+    taker_fee_rate: FPDecimal,
+    relayer_fee_share_rate: FPDecimal,
+    maker_discount_rate: FPDecimal,
+    taker_discount_rate: FPDecimal,
+    atomic_multiplier: FPDecimal,
+}
+
+impl FeeCalculator {
+    pub fn new(market: &impl GenericMarket, params: &Params) -> Self {
+        FeeCalculator {
+            market_id: market.get_market_id().clone(),
+            quote_denom: market.get_quote_denom().to_string(),
+            maker_fee_rate: market.get_maker_fee_rate(),
+            taker_fee_rate: market.get_taker_fee_rate(),
+            relayer_fee_share_rate: market.get_relayer_fee_share_rate().unwrap_or(params.relayer_fee_share_rate),
+            maker_discount_rate: FPDecimal::ZERO,
+            taker_discount_rate: FPDecimal::ZERO,
+            atomic_multiplier: FPDecimal::ONE,
+        }
+    }
+
+    /// Applies the discount rates of the account's tier, unless the market is not eligible for discounts
+    pub fn with_fee_discount(mut self, schedule: &FeeDiscountSchedule, account: &FeeDiscountAccountInfoResponse) -> Self {
+        match &account.account_info {
+            Some(tier_info) if schedule.is_eligible(&self.quote_denom, &self.market_id) => {
+                self.maker_discount_rate = tier_info.maker_discount_rate;
+                self.taker_discount_rate = tier_info.taker_discount_rate;
+            }
+            _ => {
+                self.maker_discount_rate = FPDecimal::ZERO;
+                self.taker_discount_rate = FPDecimal::ZERO;
+            }
+        }
+        self
+    }
+
+    /// Scales the taker fee for atomic market orders, which always execute as taker
+    pub fn with_atomic_multiplier(mut self, atomic_multiplier: &QueryMarketAtomicExecutionFeeMultiplierResponse) -> Self {
+        self.atomic_multiplier = atomic_multiplier.multiplier;
+        self
+    }
+
+    /// Negative maker fee rates are rebates and are not discounted
+    pub fn maker_fee_rate(&self) -> FPDecimal {
+        if self.maker_fee_rate.is_negative() {
+            return self.maker_fee_rate;
+        }
+        self.maker_fee_rate * (FPDecimal::ONE - self.maker_discount_rate)
+    }
+
+    pub fn taker_fee_rate(&self) -> FPDecimal {
+        self.taker_fee_rate * self.atomic_multiplier * (FPDecimal::ONE - self.taker_discount_rate)
+    }
+
+    /// Orders without a fee recipient default to the trader, who gets the relayer share back
+    pub fn maker_fee(&self, notional: FPDecimal, fee_recipient: Option<&Addr>) -> TradeFee {
+        self.trade_fee(self.maker_fee_rate(), notional, fee_recipient)
+    }
+
+    /// Orders without a fee recipient default to the trader, who gets the relayer share back
+    pub fn taker_fee(&self, notional: FPDecimal, fee_recipient: Option<&Addr>) -> TradeFee {
+        self.trade_fee(self.taker_fee_rate(), notional, fee_recipient)
+    }
+
+    fn trade_fee(&self, fee_rate: FPDecimal, notional: FPDecimal, fee_recipient: Option<&Addr>) -> TradeFee {
+        if fee_rate.is_negative() {
+            return TradeFee {
+                fee_rate,
+                fee: FPDecimal::ZERO,
+                rebate: notional * fee_rate.abs(),
+                relayer_fee: FPDecimal::ZERO,
+            };
+        }
+
+        let fee = notional * fee_rate;
+        let relayer_fee = fee * self.relayer_fee_share_rate;
+
+        match fee_recipient {
+            Some(_) => TradeFee {
+                fee_rate,
+                fee,
+                rebate: FPDecimal::ZERO,
+                relayer_fee,
+            },
+            None => TradeFee {
+                fee_rate,
+                fee: fee - relayer_fee,
+                rebate: FPDecimal::ZERO,
+                relayer_fee: FPDecimal::ZERO,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::types::{FeeDiscountTierInfo, FeeDiscountTierTTL};
+    use crate::exchange_mock_querier::handlers::{create_fee_discount_account_info_handler, create_fee_discount_schedule_handler};
+    use crate::test_helpers::testing_helpers::{create_mock_spot_market, inj_mock_deps};
+    use crate::InjectiveQuerier;
+
+    fn params() -> Params {
+        let deps = inj_mock_deps(|_| {});
+        let deps = deps.as_ref();
+        InjectiveQuerier::new(&deps.querier).query_exchange_params().unwrap().params.unwrap()
+    }
+
+    fn schedule(quote_denom: &str) -> FeeDiscountSchedule {
+        FeeDiscountSchedule {
+            bucket_count: 28,
+            bucket_duration: 86400,
+            quote_denoms: vec![quote_denom.to_string()],
+            tier_infos: vec![],
+            disqualified_market_ids: vec![],
+        }
+    }
+
+    fn tier_info() -> FeeDiscountTierInfo {
+        FeeDiscountTierInfo {
+            maker_discount_rate: FPDecimal::must_from_str("0.2"),
+            taker_discount_rate: FPDecimal::must_from_str("0.1"),
+            staked_amount: FPDecimal::must_from_str("1000"),
+            volume: FPDecimal::must_from_str("100000"),
+        }
+    }
+
+    #[test]
+    fn applies_discount_tier_and_relayer_share() {
+        let mut market = create_mock_spot_market("usdt", 0);
+        market.maker_fee_rate = FPDecimal::must_from_str("0.001");
+        market.taker_fee_rate = FPDecimal::must_from_str("0.002");
+        market.relayer_fee_share_rate = FPDecimal::must_from_str("0.5");
+        let fee_recipient = Addr::unchecked("relayer");
+        let account = FeeDiscountAccountInfoResponse {
+            tier_level: 1,
+            account_info: Some(tier_info()),
+            account_ttl: Some(FeeDiscountTierTTL {
+                tier: 1,
+                ttl_timestamp: 1_700_000_000,
+            }),
+        };
+
+        let calculator = FeeCalculator::new(&market, &params()).with_fee_discount(&schedule("usdt"), &account);
+        assert_eq!(calculator.maker_fee_rate(), FPDecimal::must_from_str("0.0008"));
+        assert_eq!(calculator.taker_fee_rate(), FPDecimal::must_from_str("0.0018"));
+
+        let fee = calculator.taker_fee(FPDecimal::must_from_str("1000"), Some(&fee_recipient));
+        assert_eq!(fee.fee, FPDecimal::must_from_str("1.8"));
+        assert_eq!(fee.relayer_fee, FPDecimal::must_from_str("0.9"));
+        assert_eq!(fee.protocol_fee(), FPDecimal::must_from_str("0.9"));
+
+        // without a fee recipient the relayer share goes back to the trader
+        let fee = calculator.taker_fee(FPDecimal::must_from_str("1000"), None);
+        assert_eq!(fee.fee, FPDecimal::must_from_str("0.9"));
+        assert_eq!(fee.relayer_fee, FPDecimal::ZERO);
+        assert_eq!(fee.protocol_fee(), FPDecimal::must_from_str("0.9"));
+
+        // derivative markets use their own rate, or the exchange default for markets without one
+        let deps = inj_mock_deps(|_| {});
+        let deps = deps.as_ref();
+        let mut derivative_market = InjectiveQuerier::new(&deps.querier)
+            .query_derivative_market(&market.market_id)
+            .unwrap()
+            .market
+            .and_then(|market| market.market)
+            .unwrap();
+        derivative_market.relayer_fee_share_rate = Some(FPDecimal::must_from_str("0.25"));
+        let fee = FeeCalculator::new(&derivative_market, &params()).taker_fee(FPDecimal::must_from_str("1000"), Some(&fee_recipient));
+        assert_eq!(fee.relayer_fee, FPDecimal::must_from_str("0.5"));
+
+        derivative_market.relayer_fee_share_rate = None;
+        let fee = FeeCalculator::new(&derivative_market, &params()).taker_fee(FPDecimal::must_from_str("1000"), Some(&fee_recipient));
+        assert_eq!(fee.relayer_fee, FPDecimal::must_from_str("0.8"));
+
+        // markets quoted in other denoms don't get discounts
+        let calculator = FeeCalculator::new(&market, &params()).with_fee_discount(&schedule("peggy0xdac"), &account);
+        assert_eq!(calculator.taker_fee_rate(), FPDecimal::must_from_str("0.002"));
+
+        let mut disqualified = schedule("usdt");
+        disqualified.disqualified_market_ids.push(market.market_id.clone());
+        let calculator = FeeCalculator::new(&market, &params()).with_fee_discount(&disqualified, &account);
+        assert_eq!(calculator.maker_fee_rate(), FPDecimal::must_from_str("0.001"));
+    }
+
+    #[test]
+    fn handles_maker_rebates_and_atomic_multiplier() {
+        let mut market = create_mock_spot_market("usdt", 0);
+        market.maker_fee_rate = FPDecimal::must_from_str("-0.0001");
+        market.taker_fee_rate = FPDecimal::must_from_str("0.001");
+
+        let deps = inj_mock_deps(|querier| {
+            querier.fee_discount_schedule_response_handler = create_fee_discount_schedule_handler(schedule("usdt"));
+            querier.fee_discount_account_info_response_handler = create_fee_discount_account_info_handler(1, tier_info());
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+        let schedule = querier.query_fee_discount_schedule().unwrap().fee_discount_schedule.unwrap();
+        let account = querier.query_fee_discount_account_info(&"inj1trader").unwrap();
+        let multiplier = querier.query_market_atomic_execution_fee_multiplier(&market.market_id).unwrap();
+
+        let calculator = FeeCalculator::new(&market, &params())
+            .with_fee_discount(&schedule, &account)
+            .with_atomic_multiplier(&multiplier);
+
+        let fee = calculator.maker_fee(FPDecimal::must_from_str("1000"), Some(&Addr::unchecked("relayer")));
+        assert_eq!(fee.fee_rate, FPDecimal::must_from_str("-0.0001"));
+        assert_eq!(fee.fee, FPDecimal::ZERO);
+        assert_eq!(fee.rebate, FPDecimal::must_from_str("0.1"));
+        assert_eq!(fee.relayer_fee, FPDecimal::ZERO);
+
+        // 0.001 * 2 (default mock multiplier) * (1 - 0.1)
+        assert_eq!(calculator.taker_fee_rate(), FPDecimal::must_from_str("0.0018"));
+    }
+}
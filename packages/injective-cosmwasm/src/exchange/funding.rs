@@ -0,0 +1,162 @@
+use injective_math::FPDecimal;
+
+use crate::exchange::{
+    derivative::Position,
+    derivative_market::{PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState},
+};
+
+const SECONDS_PER_YEAR: i128 = 365 * 24 * 60 * 60;
+
+/// Funding expected at the end of the current funding interval, if the premium stays at its current TWAP
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FundingProjection {
+    /// Time weighted premium of the market over the mark price since the interval started
+    pub premium_twap: FPDecimal,
+    /// Premium TWAP plus the hourly interest rate, capped by the hourly funding rate cap
+    pub funding_rate: FPDecimal,
+    /// Funding paid by longs to shorts per unit of position, negative when shorts pay longs
+    pub funding_per_unit: FPDecimal,
+    pub next_funding_timestamp: i64,
+    pub funding_interval: i64,
+}
+
+impl FundingProjection {
+    pub fn new(market_info: &PerpetualMarketInfo, funding: &PerpetualMarketFunding, mark_price: FPDecimal) -> Self {
+        let premium_twap = funding_premium_twap(market_info, funding);
+        let funding_rate = cap_funding_rate(premium_twap + market_info.hourly_interest_rate, market_info.hourly_funding_rate_cap);
+
+        FundingProjection {
+            premium_twap,
+            funding_rate,
+            funding_per_unit: funding_rate * mark_price,
+            next_funding_timestamp: market_info.next_funding_timestamp,
+            funding_interval: market_info.funding_interval,
+        }
+    }
+
+    /// Funding the position pays at the next funding, negative when it receives funding
+    pub fn payment(&self, position: &Position) -> FPDecimal {
+        if position.isLong {
+            position.quantity * self.funding_per_unit
+        } else {
+            -(position.quantity * self.funding_per_unit)
+        }
+    }
+
+    /// Funding rate extrapolated over a year of funding intervals
+    pub fn annualized_rate(&self) -> FPDecimal {
+        annualize_funding_rate(self.funding_rate, self.funding_interval)
+    }
+
+    /// Funding payment of the position extrapolated over a year of funding intervals
+    pub fn annualized_payment(&self, position: &Position) -> FPDecimal {
+        annualize_funding_rate(self.payment(position), self.funding_interval)
+    }
+}
+
+impl PerpetualMarketState {
+    pub fn project_funding(&self, mark_price: FPDecimal) -> FundingProjection {
+        FundingProjection::new(&self.market_info, &self.funding_info, mark_price)
+    }
+}
+
+/// Mirrors the exchange module, which accumulates `premium * seconds` in `cumulative_price` over the current
+/// funding interval and divides by 24 to turn the daily premium into an hourly rate
+pub fn funding_premium_twap(market_info: &PerpetualMarketInfo, funding: &PerpetualMarketFunding) -> FPDecimal {
+    let interval_start = market_info.next_funding_timestamp - market_info.funding_interval;
+    let elapsed = funding.last_timestamp - interval_start;
+    if elapsed <= 0 {
+        return FPDecimal::ZERO;
+    }
+
+    funding.cumulative_price / FPDecimal::from(24 * i128::from(elapsed))
+}
+
+/// Clamps the funding rate to `[-cap, cap]` like the exchange module does
+pub fn cap_funding_rate(funding_rate: FPDecimal, hourly_funding_rate_cap: FPDecimal) -> FPDecimal {
+    funding_rate.minimum(&hourly_funding_rate_cap).maximum(&-hourly_funding_rate_cap)
+}
+
+/// Scales a per interval amount to a year, zero for markets without a funding interval
+pub fn annualize_funding_rate(funding_rate: FPDecimal, funding_interval: i64) -> FPDecimal {
+    if funding_interval <= 0 {
+        return FPDecimal::ZERO;
+    }
+
+    funding_rate * FPDecimal::from(SECONDS_PER_YEAR) / FPDecimal::from(i128::from(funding_interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::types::MarketId;
+    use crate::test_helpers::testing_helpers::TEST_MARKET_ID_1;
+
+    fn market_info() -> PerpetualMarketInfo {
+        PerpetualMarketInfo {
+            market_id: MarketId::unchecked(TEST_MARKET_ID_1),
+            hourly_funding_rate_cap: FPDecimal::must_from_str("0.000625"),
+            hourly_interest_rate: FPDecimal::must_from_str("0.0001"),
+            next_funding_timestamp: 7200,
+            funding_interval: 3600,
+        }
+    }
+
+    fn funding(cumulative_price: &str) -> PerpetualMarketFunding {
+        PerpetualMarketFunding {
+            cumulative_funding: FPDecimal::ZERO,
+            cumulative_price: FPDecimal::must_from_str(cumulative_price),
+            last_timestamp: 5400,
+        }
+    }
+
+    fn position(is_long: bool) -> Position {
+        Position {
+            isLong: is_long,
+            quantity: FPDecimal::must_from_str("10"),
+            entry_price: FPDecimal::must_from_str("100"),
+            margin: FPDecimal::must_from_str("200"),
+            cumulative_funding_entry: FPDecimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn projects_funding_from_premium_twap() {
+        // a 1.2% premium held for the 1800 seconds elapsed in the interval
+        let projection = FundingProjection::new(&market_info(), &funding("21.6"), FPDecimal::must_from_str("100"));
+
+        assert_eq!(projection.premium_twap, FPDecimal::must_from_str("0.0005"));
+        assert_eq!(projection.funding_rate, FPDecimal::must_from_str("0.0006"));
+        assert_eq!(projection.funding_per_unit, FPDecimal::must_from_str("0.06"));
+        assert_eq!(projection.payment(&position(true)), FPDecimal::must_from_str("0.6"));
+        assert_eq!(projection.payment(&position(false)), FPDecimal::must_from_str("-0.6"));
+        assert_eq!(projection.annualized_rate(), FPDecimal::must_from_str("5.256"));
+        assert_eq!(projection.annualized_payment(&position(false)), FPDecimal::must_from_str("-5256"));
+
+        // position value after the funding matches the projected payment
+        let mut long = position(true);
+        long.apply_funding(projection.funding_per_unit);
+        assert_eq!(long.margin, position(true).margin - projection.payment(&position(true)));
+    }
+
+    #[test]
+    fn caps_funding_rate() {
+        let mark_price = FPDecimal::must_from_str("100");
+
+        let projection = FundingProjection::new(&market_info(), &funding("100"), mark_price);
+        assert_eq!(projection.funding_rate, FPDecimal::must_from_str("0.000625"));
+
+        let projection = FundingProjection::new(&market_info(), &funding("-100"), mark_price);
+        assert_eq!(projection.funding_rate, FPDecimal::must_from_str("-0.000625"));
+
+        // nothing accumulated yet at the start of the interval, only interest is charged
+        let mut funding = funding("0");
+        funding.last_timestamp = 3600;
+        let projection = FundingProjection::new(&market_info(), &funding, mark_price);
+        assert_eq!(projection.premium_twap, FPDecimal::ZERO);
+        assert_eq!(projection.funding_rate, FPDecimal::must_from_str("0.0001"));
+
+        assert_eq!(cap_funding_rate(FPDecimal::must_from_str("0.01"), FPDecimal::ZERO), FPDecimal::ZERO);
+        assert_eq!(annualize_funding_rate(FPDecimal::ONE, 0), FPDecimal::ZERO);
+    }
+}
@@ -1,6 +1,8 @@
+use cosmwasm_std::StdResult;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use tiny_keccak::Keccak;
 
 use crate::MarketId;
 
@@ -24,4 +26,135 @@ pub trait GenericMarket {
     fn get_status(&self) -> MarketStatus;
     fn get_min_price_tick_size(&self) -> FPDecimal;
     fn min_quantity_tick_size(&self) -> FPDecimal;
+    fn get_min_notional(&self) -> FPDecimal;
+
+    /// Share of trading fees paid to relayers, `None` for market types without their own rate
+    fn get_relayer_fee_share_rate(&self) -> Option<FPDecimal> {
+        None
+    }
+
+    /// Returns true if the market passes both filters, a `None` filter matches every market
+    fn matches_filter(&self, status: Option<MarketStatus>, quote_denom: Option<&str>) -> bool {
+        let status_matches = match status {
+            Some(status) => self.get_status() == status,
+            None => true,
+        };
+        let quote_denom_matches = match quote_denom {
+            Some(quote_denom) => self.get_quote_denom() == quote_denom,
+            None => true,
+        };
+
+        status_matches && quote_denom_matches
+    }
+}
+
+/// Market IDs are the keccak256 hash of the concatenated market parameters
+pub(crate) fn hash_market_id(parts: &[&str]) -> StdResult<MarketId> {
+    let mut hasher = Keccak::new_keccak256();
+    for part in parts {
+        hasher.update(part.as_bytes());
+    }
+    let mut res = [0u8; 32];
+    hasher.finalize(&mut res);
+
+    MarketId::new(format!("0x{}", hex::encode(res)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{binary_options_market::BinaryOptionsMarket, derivative_market::DerivativeMarket};
+    use crate::oracle::types::OracleType;
+    use crate::test_helpers::testing_helpers::{create_mock_spot_market, test_market_ids};
+
+    fn derivative_market(status: MarketStatus, quote_denom: &str) -> DerivativeMarket {
+        DerivativeMarket {
+            ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "inj".to_string(),
+            oracle_quote: "usdt".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            oracle_scale_factor: 0,
+            quote_denom: quote_denom.to_string(),
+            market_id: test_market_ids()[1].clone(),
+            initial_margin_ratio: FPDecimal::must_from_str("0.05"),
+            maintenance_margin_ratio: FPDecimal::must_from_str("0.02"),
+            maker_fee_rate: FPDecimal::ZERO,
+            taker_fee_rate: FPDecimal::ZERO,
+            isPerpetual: true,
+            status,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.01"),
+            min_notional: FPDecimal::ZERO,
+            relayer_fee_share_rate: None,
+        }
+    }
+
+    fn binary_options_market(status: MarketStatus, quote_denom: &str) -> BinaryOptionsMarket {
+        BinaryOptionsMarket {
+            ticker: "BTC>100k".to_string(),
+            oracle_symbol: "BTC".to_string(),
+            oracle_provider: "frontrunner".to_string(),
+            oracle_type: OracleType::Provider,
+            oracle_scale_factor: 6,
+            expiration_timestamp: 1700000000,
+            settlement_timestamp: 1700003600,
+            admin: String::new(),
+            quote_denom: quote_denom.to_string(),
+            market_id: test_market_ids()[2].clone(),
+            maker_fee_rate: FPDecimal::ZERO,
+            taker_fee_rate: FPDecimal::ZERO,
+            relayer_fee_share_rate: FPDecimal::must_from_str("0.4"),
+            status,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::ONE,
+            settlement_price: None,
+            min_notional: FPDecimal::ZERO,
+        }
+    }
+
+    /// Checks an active usdt market against every combination of filters
+    fn assert_filters(active: &impl GenericMarket, paused: &impl GenericMarket, usdc: &impl GenericMarket) {
+        assert!(active.matches_filter(None, None));
+        assert!(active.matches_filter(Some(MarketStatus::Active), None));
+        assert!(active.matches_filter(Some(MarketStatus::Active), Some("usdt")));
+        assert!(!active.matches_filter(Some(MarketStatus::Paused), None));
+        assert!(!active.matches_filter(None, Some("usdc")));
+
+        assert!(paused.matches_filter(None, Some("usdt")));
+        assert!(paused.matches_filter(Some(MarketStatus::Paused), Some("usdt")));
+        assert!(!paused.matches_filter(Some(MarketStatus::Active), Some("usdt")));
+
+        assert!(usdc.matches_filter(Some(MarketStatus::Active), Some("usdc")));
+        assert!(!usdc.matches_filter(Some(MarketStatus::Active), Some("usdt")));
+        assert!(!usdc.matches_filter(Some(MarketStatus::Paused), Some("usdc")));
+    }
+
+    #[test]
+    fn spot_markets_match_filters() {
+        let active = create_mock_spot_market("inj", 0);
+        let mut paused = create_mock_spot_market("atom", 1);
+        paused.status = MarketStatus::Paused;
+        let mut usdc = create_mock_spot_market("weth", 2);
+        usdc.quote_denom = "usdc".to_string();
+
+        assert_filters(&active, &paused, &usdc);
+    }
+
+    #[test]
+    fn derivative_markets_match_filters() {
+        assert_filters(
+            &derivative_market(MarketStatus::Active, "usdt"),
+            &derivative_market(MarketStatus::Paused, "usdt"),
+            &derivative_market(MarketStatus::Active, "usdc"),
+        );
+    }
+
+    #[test]
+    fn binary_options_markets_match_filters() {
+        assert_filters(
+            &binary_options_market(MarketStatus::Active, "usdt"),
+            &binary_options_market(MarketStatus::Paused, "usdt"),
+            &binary_options_market(MarketStatus::Active, "usdc"),
+        );
+    }
 }
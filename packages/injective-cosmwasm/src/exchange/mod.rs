@@ -1,11 +1,20 @@
+pub mod batch;
+pub mod binary_options_market;
 pub mod cancel;
 pub mod derivative;
 pub mod derivative_market;
+pub mod fees;
+pub mod funding;
 pub mod market;
 pub mod order;
+pub mod orderbook;
 pub mod privileged_action;
+pub mod reply;
 pub mod response;
+pub mod rewards;
+pub mod risk;
 pub mod spot;
 pub mod spot_market;
 pub mod subaccount;
 pub mod types;
+pub mod validation;
@@ -1,5 +1,13 @@
-use cosmwasm_std::Addr;
-use injective_math::FPDecimal;
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, StdError, StdResult};
+use injective_math::{
+    display::ToProto,
+    scale::{Scaled, DEC_SCALE_FACTOR},
+    FPDecimal,
+};
+use injective_std::types::injective::exchange::v1beta1 as exchange;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -16,6 +24,83 @@ pub enum OrderSide {
     Sell = 2,
 }
 
+/// Bit flags selecting the orders a cancellation applies to, serialized as the integer the chain expects
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(transparent)]
+pub struct OrderMask(i32);
+
+impl OrderMask {
+    pub const UNUSED: OrderMask = OrderMask(0);
+    pub const ANY: OrderMask = OrderMask(1);
+    pub const REGULAR: OrderMask = OrderMask(2);
+    pub const CONDITIONAL: OrderMask = OrderMask(4);
+    /// Buy side for regular orders, trigger price above mark price for conditional orders
+    pub const DIRECTION_BUY_OR_HIGHER: OrderMask = OrderMask(8);
+    /// Sell side for regular orders, trigger price below mark price for conditional orders
+    pub const DIRECTION_SELL_OR_LOWER: OrderMask = OrderMask(16);
+    pub const TYPE_MARKET: OrderMask = OrderMask(32);
+    pub const TYPE_LIMIT: OrderMask = OrderMask(64);
+
+    const ALL_BITS: i32 = 127;
+
+    /// Returns `None` if any bit outside the known flags is set
+    pub const fn from_bits(bits: i32) -> Option<OrderMask> {
+        if bits & !Self::ALL_BITS != 0 {
+            return None;
+        }
+        Some(OrderMask(bits))
+    }
+
+    pub const fn bits(&self) -> i32 {
+        self.0
+    }
+
+    pub const fn contains(&self, other: OrderMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Selects a resting limit order on the given side
+    pub fn regular(is_buy: bool) -> OrderMask {
+        let direction = if is_buy {
+            OrderMask::DIRECTION_BUY_OR_HIGHER
+        } else {
+            OrderMask::DIRECTION_SELL_OR_LOWER
+        };
+        OrderMask::REGULAR | direction
+    }
+
+    /// Selects an untriggered conditional order, both trigger directions are matched if `is_trigger_price_higher` is `None`
+    pub fn conditional(is_trigger_price_higher: Option<bool>, is_limit: bool) -> OrderMask {
+        let mut mask = OrderMask::CONDITIONAL;
+        match is_trigger_price_higher {
+            Some(true) => mask |= OrderMask::DIRECTION_BUY_OR_HIGHER,
+            Some(false) => mask |= OrderMask::DIRECTION_SELL_OR_LOWER,
+            None => {}
+        }
+        mask | if is_limit { OrderMask::TYPE_LIMIT } else { OrderMask::TYPE_MARKET }
+    }
+}
+
+impl BitOr for OrderMask {
+    type Output = OrderMask;
+
+    fn bitor(self, rhs: OrderMask) -> OrderMask {
+        OrderMask(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for OrderMask {
+    fn bitor_assign(&mut self, rhs: OrderMask) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<OrderMask> for i32 {
+    fn from(mask: OrderMask) -> Self {
+        mask.0
+    }
+}
+
 #[derive(Serialize_repr, Deserialize_repr, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[repr(u8)]
 pub enum OrderType {
@@ -32,6 +117,71 @@ pub enum OrderType {
     SellAtomic = 10,
 }
 
+impl OrderType {
+    /// Includes conditional buy orders, which `GenericOrder::is_buy` does not cover
+    pub fn is_buy(&self) -> bool {
+        matches!(
+            self,
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic
+        )
+    }
+
+    pub fn is_post_only(&self) -> bool {
+        matches!(self, OrderType::BuyPo | OrderType::SellPo)
+    }
+
+    pub fn is_atomic(&self) -> bool {
+        matches!(self, OrderType::BuyAtomic | OrderType::SellAtomic)
+    }
+
+    pub fn is_conditional(&self) -> bool {
+        matches!(self, OrderType::StopBuy | OrderType::StopSell | OrderType::TakeBuy | OrderType::TakeSell)
+    }
+
+    /// Whether a conditional order triggers once the mark price rises to its trigger price, as opposed to falling to it
+    pub fn is_trigger_price_higher(&self) -> bool {
+        matches!(self, OrderType::StopBuy | OrderType::TakeSell)
+    }
+
+    /// Order mask which selects exactly this conditional order type when cancelling, `None` for non-conditional types
+    pub fn conditional_order_mask(&self, is_limit: bool) -> Option<OrderMask> {
+        if !self.is_conditional() {
+            return None;
+        }
+
+        Some(OrderMask::conditional(Some(self.is_trigger_price_higher()), is_limit))
+    }
+}
+
+impl From<OrderType> for i32 {
+    fn from(order_type: OrderType) -> Self {
+        order_type as i32
+    }
+}
+
+impl TryFrom<i32> for OrderType {
+    type Error = StdError;
+
+    fn try_from(value: i32) -> StdResult<Self> {
+        let order_type = match value {
+            0 => OrderType::Undefined,
+            1 => OrderType::Buy,
+            2 => OrderType::Sell,
+            3 => OrderType::StopBuy,
+            4 => OrderType::StopSell,
+            5 => OrderType::TakeBuy,
+            6 => OrderType::TakeSell,
+            7 => OrderType::BuyPo,
+            8 => OrderType::SellPo,
+            9 => OrderType::BuyAtomic,
+            10 => OrderType::SellAtomic,
+            _ => return Err(StdError::generic_err(format!("Invalid order type: {value}"))),
+        };
+
+        Ok(order_type)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OrderData {
     pub market_id: MarketId,
@@ -56,6 +206,18 @@ impl From<OrderData> for ShortOrderData {
     }
 }
 
+impl From<OrderData> for exchange::OrderData {
+    fn from(order: OrderData) -> Self {
+        exchange::OrderData {
+            market_id: order.market_id.into(),
+            subaccount_id: order.subaccount_id.into(),
+            order_hash: order.order_hash,
+            order_mask: OrderMask::UNUSED.into(),
+            cid: String::new(),
+        }
+    }
+}
+
 pub fn order_data_to_short(order_data: Vec<OrderData>) -> Vec<ShortOrderData> {
     order_data.into_iter().map(|item| item.into()).collect()
 }
@@ -70,6 +232,32 @@ pub struct OrderInfo {
     pub cid: Option<String>,
 }
 
+impl From<OrderInfo> for exchange::OrderInfo {
+    fn from(order_info: OrderInfo) -> Self {
+        exchange::OrderInfo {
+            subaccount_id: order_info.subaccount_id.into(),
+            fee_recipient: order_info.fee_recipient.map(String::from).unwrap_or_default(),
+            price: order_info.price.to_proto_string(),
+            quantity: order_info.quantity.to_proto_string(),
+            cid: order_info.cid.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<exchange::OrderInfo> for OrderInfo {
+    type Error = StdError;
+
+    fn try_from(order_info: exchange::OrderInfo) -> StdResult<Self> {
+        Ok(OrderInfo {
+            subaccount_id: SubaccountId::new(order_info.subaccount_id)?,
+            fee_recipient: non_empty(order_info.fee_recipient).map(Addr::unchecked),
+            price: dec_from_proto(&order_info.price)?,
+            quantity: dec_from_proto(&order_info.quantity)?,
+            cid: non_empty(order_info.cid),
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct ShortOrderInfo {
     pub subaccount_id: ShortSubaccountId,
@@ -92,6 +280,36 @@ impl From<OrderInfo> for ShortOrderInfo {
     }
 }
 
+/// Parses a protobuf decimal, which is encoded as an integer scaled by 10^18. Empty strings are read as zero
+pub fn dec_from_proto(value: &str) -> StdResult<FPDecimal> {
+    if value.is_empty() {
+        return Ok(FPDecimal::ZERO);
+    }
+
+    Ok(FPDecimal::from_str(value)?.scaled(-DEC_SCALE_FACTOR))
+}
+
+/// Optional protobuf decimals such as trigger prices are left empty when unset
+pub fn optional_dec_to_proto(value: Option<FPDecimal>) -> String {
+    value.map(ToProto::to_proto_string).unwrap_or_default()
+}
+
+pub fn optional_dec_from_proto(value: &str) -> StdResult<Option<FPDecimal>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    dec_from_proto(value).map(Some)
+}
+
+pub(crate) fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 pub trait GenericOrder {
     fn get_order_type(&self) -> &OrderType;
     fn get_order_info(&self) -> &OrderInfo;
@@ -106,15 +324,233 @@ pub trait GenericTrimmedOrder {
     fn is_buy(&self) -> bool;
     fn is_sell(&self) -> bool;
     fn get_order_hash(&self) -> String;
+    fn get_cid(&self) -> Option<&str>;
+}
+
+/// Result of matching the cids a contract tracks locally against a subaccount's resting orders
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CidReconciliation {
+    /// Tracked cids that still rest on the book, with their order hash
+    pub resting: Vec<(String, String)>,
+    /// Tracked cids without a resting order, they were filled, cancelled or never placed
+    pub closed: Vec<String>,
+    /// Resting orders whose cid is not tracked locally, with their order hash
+    pub untracked: Vec<(String, String)>,
+}
+
+pub fn reconcile_cids<O: GenericTrimmedOrder>(tracked_cids: &[String], orders: &[O]) -> CidReconciliation {
+    let mut reconciliation = CidReconciliation::default();
+
+    for cid in tracked_cids {
+        match orders.iter().find(|order| order.get_cid() == Some(cid.as_str())) {
+            Some(order) => reconciliation.resting.push((cid.to_owned(), order.get_order_hash())),
+            None => reconciliation.closed.push(cid.to_owned()),
+        }
+    }
+
+    reconciliation.untracked = orders
+        .iter()
+        .filter_map(|order| order.get_cid().map(|cid| (cid, order)))
+        .filter(|(cid, _)| !tracked_cids.iter().any(|tracked| tracked == cid))
+        .map(|(cid, order)| (cid.to_string(), order.get_order_hash()))
+        .collect();
+
+    reconciliation
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::exchange::order::OrderType;
+    use cosmwasm_std::Addr;
+    use injective_math::FPDecimal;
+    use injective_std::types::injective::exchange::v1beta1 as exchange;
+
+    use crate::exchange::order::{reconcile_cids, CidReconciliation, OrderInfo, OrderMask, OrderType};
+    use crate::exchange::types::{MarketId, SubaccountId};
+    use crate::exchange_mock_querier::handlers::create_trader_spot_orders_handler;
+    use crate::test_helpers::testing_helpers::inj_mock_deps;
+    use crate::{DerivativeOrder, InjectiveQuerier, SpotOrder, TrimmedSpotLimitOrder};
+
+    const MARKET_ID: &str = "0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe";
+    const SUBACCOUNT_ID: &str = "0xb5c4ad1b3e3c8c0e3e8e1a0f8e8e0f8e1a0f8e8e000000000000000000000001";
 
     #[test]
     fn order_type_serialization() {
         let types = vec![OrderType::Undefined, OrderType::Buy, OrderType::SellPo, OrderType::SellAtomic];
         assert_eq!(serde_json_wasm::to_string(&types).unwrap(), "[0,1,8,10]");
     }
+
+    #[test]
+    fn order_type_proto_conversion() {
+        for value in 0..=10 {
+            let order_type = OrderType::try_from(value).unwrap();
+            assert_eq!(i32::from(order_type), value);
+        }
+
+        assert!(OrderType::try_from(11).is_err());
+    }
+
+    #[test]
+    fn spot_order_proto_conversion() {
+        let order = SpotOrder {
+            market_id: MarketId::unchecked(MARKET_ID),
+            order_info: OrderInfo {
+                subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+                fee_recipient: None,
+                price: FPDecimal::must_from_str("0.000000000009800000"),
+                quantity: FPDecimal::must_from_str("1000000000000000000"),
+                cid: Some("order-1".to_string()),
+            },
+            order_type: OrderType::SellPo,
+            trigger_price: None,
+        };
+
+        let proto: exchange::SpotOrder = order.clone().into();
+        assert_eq!(proto.market_id, MARKET_ID);
+        assert_eq!(proto.order_type, 8);
+        assert_eq!(proto.trigger_price, "");
+
+        let order_info = proto.order_info.clone().unwrap();
+        assert_eq!(order_info.price, "9800000");
+        assert_eq!(order_info.quantity, "1000000000000000000000000000000000000");
+        assert_eq!(order_info.fee_recipient, "");
+        assert_eq!(order_info.cid, "order-1");
+
+        assert_eq!(SpotOrder::try_from(proto).unwrap(), order);
+    }
+
+    #[test]
+    fn derivative_order_proto_conversion() {
+        let order = DerivativeOrder {
+            market_id: MarketId::unchecked(MARKET_ID),
+            order_info: OrderInfo {
+                subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+                fee_recipient: Some(Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv")),
+                price: FPDecimal::must_from_str("12.5"),
+                quantity: FPDecimal::must_from_str("0.001"),
+                cid: None,
+            },
+            order_type: OrderType::StopBuy,
+            margin: FPDecimal::must_from_str("2.5"),
+            trigger_price: Some(FPDecimal::from(12u128)),
+        };
+
+        let proto: exchange::DerivativeOrder = order.clone().into();
+        assert_eq!(proto.margin, "2500000000000000000");
+        assert_eq!(proto.trigger_price, "12000000000000000000");
+        assert_eq!(proto.order_info.clone().unwrap().quantity, "1000000000000000");
+
+        assert_eq!(DerivativeOrder::try_from(proto).unwrap(), order);
+    }
+
+    #[test]
+    fn invalid_proto_orders_are_rejected() {
+        let proto = exchange::SpotOrder {
+            market_id: MARKET_ID.to_string(),
+            order_info: None,
+            order_type: 1,
+            trigger_price: "".to_string(),
+        };
+        assert!(SpotOrder::try_from(proto.clone()).is_err());
+
+        let proto = exchange::SpotOrder {
+            order_info: Some(exchange::OrderInfo {
+                subaccount_id: SUBACCOUNT_ID.to_string(),
+                fee_recipient: "".to_string(),
+                price: "not-a-number".to_string(),
+                quantity: "1".to_string(),
+                cid: "".to_string(),
+            }),
+            ..proto
+        };
+        assert!(SpotOrder::try_from(proto).is_err());
+    }
+
+    #[test]
+    fn conditional_order_masks() {
+        assert_eq!(OrderType::Buy.conditional_order_mask(true), None);
+        // conditional | higher | limit
+        assert_eq!(
+            OrderType::StopBuy.conditional_order_mask(true),
+            Some(OrderMask::from_bits(4 | 8 | 64).unwrap())
+        );
+        // conditional | lower | market
+        assert_eq!(
+            OrderType::TakeBuy.conditional_order_mask(false),
+            Some(OrderMask::from_bits(4 | 16 | 32).unwrap())
+        );
+        assert_eq!(
+            OrderType::StopSell.conditional_order_mask(false),
+            Some(OrderMask::from_bits(4 | 16 | 32).unwrap())
+        );
+        assert_eq!(
+            OrderType::TakeSell.conditional_order_mask(true),
+            Some(OrderMask::from_bits(4 | 8 | 64).unwrap())
+        );
+    }
+
+    #[test]
+    fn order_mask_serializes_as_integer() {
+        let mask = OrderMask::regular(false);
+
+        assert_eq!(mask.bits(), 2 | 16);
+        assert!(mask.contains(OrderMask::REGULAR));
+        assert!(!mask.contains(OrderMask::CONDITIONAL));
+        assert_eq!(serde_json_wasm::to_string(&mask).unwrap(), "18");
+        assert_eq!(serde_json_wasm::from_str::<OrderMask>("18").unwrap(), mask);
+        assert_eq!(OrderMask::conditional(None, false).bits(), 4 | 32);
+        assert_eq!(OrderMask::from_bits(128), None);
+    }
+
+    fn resting_order(order_hash: &str, cid: Option<&str>) -> TrimmedSpotLimitOrder {
+        TrimmedSpotLimitOrder {
+            price: FPDecimal::must_from_str("10"),
+            quantity: FPDecimal::must_from_str("1"),
+            fillable: FPDecimal::must_from_str("1"),
+            isBuy: true,
+            order_hash: order_hash.to_string(),
+            cid: cid.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn reconciles_tracked_cids() {
+        let orders = vec![
+            resting_order("0x01", Some("a")),
+            resting_order("0x02", Some("external")),
+            resting_order("0x03", None),
+            resting_order("0x04", Some("")),
+        ];
+
+        let reconciliation = reconcile_cids(&["a".to_string(), "b".to_string()], &orders);
+        assert_eq!(
+            reconciliation,
+            CidReconciliation {
+                resting: vec![("a".to_string(), "0x01".to_string())],
+                closed: vec!["b".to_string()],
+                untracked: vec![("external".to_string(), "0x02".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn queries_orders_by_cids() {
+        let market_id = MarketId::unchecked(MARKET_ID);
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT_ID);
+        let orders = vec![
+            resting_order("0x01", Some("a")),
+            resting_order("0x02", Some("b")),
+            resting_order("0x03", None),
+        ];
+
+        let deps = inj_mock_deps(|querier| {
+            querier.trader_spot_orders_response_handler = create_trader_spot_orders_handler(Some(orders.clone()));
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let by_cids = querier
+            .query_spot_orders_by_cids(&market_id, &subaccount_id, &["a".to_string(), "c".to_string()])
+            .unwrap();
+        assert_eq!(by_cids, vec![orders[0].clone()]);
+    }
 }
@@ -0,0 +1,294 @@
+use injective_math::FPDecimal;
+
+use crate::exchange::{
+    market::GenericMarket,
+    response::{QueryMarketAtomicExecutionFeeMultiplierResponse, QueryOrderbookResponse},
+    types::PriceLevel,
+};
+
+/// Amount a taker order should fill, notional is denominated in quote and excludes fees
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillTarget {
+    Quantity(FPDecimal),
+    Notional(FPDecimal),
+}
+
+impl FillTarget {
+    fn amount(&self) -> FPDecimal {
+        match self {
+            FillTarget::Quantity(amount) | FillTarget::Notional(amount) => *amount,
+        }
+    }
+}
+
+/// Expected result of a taker order matched against resting price levels
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderbookFill {
+    pub quantity: FPDecimal,
+    pub notional: FPDecimal,
+    pub average_price: FPDecimal,
+    pub best_price: FPDecimal,
+    pub worst_price: FPDecimal,
+    /// Relative distance between average and best price
+    pub slippage: FPDecimal,
+    pub fee: FPDecimal,
+    /// False when the book ran out of liquidity before the target was reached
+    pub is_complete: bool,
+}
+
+impl OrderbookFill {
+    /// Quote paid including fees for buys, quote received net of fees for sells
+    pub fn quote_amount(&self, is_buy: bool) -> FPDecimal {
+        if is_buy {
+            self.notional + self.fee
+        } else {
+            self.notional - self.fee
+        }
+    }
+}
+
+/// Walks one side of an orderbook as a taker would, best price first.
+/// Levels are expected in the order returned by the orderbook queries: sells ascending, buys descending.
+#[derive(Clone, Debug)]
+pub struct OrderbookSimulator<'a> {
+    levels: &'a [PriceLevel],
+    is_buy: bool,
+    fee_rate: FPDecimal,
+}
+
+impl<'a> OrderbookSimulator<'a> {
+    pub fn new(levels: &'a [PriceLevel], is_buy: bool) -> Self {
+        OrderbookSimulator {
+            levels,
+            is_buy,
+            fee_rate: FPDecimal::ZERO,
+        }
+    }
+
+    /// Simulates a buy, which matches against the sell side of the orderbook
+    pub fn buy(orderbook: &'a QueryOrderbookResponse) -> Self {
+        Self::new(&orderbook.sells_price_level, true)
+    }
+
+    /// Simulates a sell, which matches against the buy side of the orderbook
+    pub fn sell(orderbook: &'a QueryOrderbookResponse) -> Self {
+        Self::new(&orderbook.buys_price_level, false)
+    }
+
+    pub fn with_fee_rate(mut self, fee_rate: FPDecimal) -> Self {
+        self.fee_rate = fee_rate;
+        self
+    }
+
+    /// Uses the market taker fee, scaled by the atomic execution multiplier when simulating an atomic order
+    pub fn with_taker_fee(self, market: &impl GenericMarket, atomic_multiplier: Option<&QueryMarketAtomicExecutionFeeMultiplierResponse>) -> Self {
+        let fee_rate = match atomic_multiplier {
+            Some(response) => market.get_taker_fee_rate() * response.multiplier,
+            None => market.get_taker_fee_rate(),
+        };
+        self.with_fee_rate(fee_rate)
+    }
+
+    /// Returns `None` if the target is not positive or there is no liquidity
+    pub fn fill(&self, target: FillTarget) -> Option<OrderbookFill> {
+        let mut remaining = target;
+        if remaining.amount() <= FPDecimal::ZERO {
+            return None;
+        }
+
+        self.walk(|level| {
+            let quantity = match &mut remaining {
+                FillTarget::Quantity(remaining) => {
+                    let quantity = level.q.minimum(remaining);
+                    *remaining -= quantity;
+                    quantity
+                }
+                FillTarget::Notional(remaining) => {
+                    let max_quantity = *remaining / level.p;
+                    if level.q >= max_quantity {
+                        // avoids carrying a rounding remainder over to the next level
+                        *remaining = FPDecimal::ZERO;
+                        max_quantity
+                    } else {
+                        *remaining -= level.q * level.p;
+                        level.q
+                    }
+                }
+            };
+            (quantity, remaining.amount().is_zero())
+        })
+    }
+
+    /// Fills all liquidity priced at or better than `price_limit`, which is the max quantity a limit order would take
+    pub fn fill_within_price(&self, price_limit: FPDecimal) -> Option<OrderbookFill> {
+        self.walk(|level| {
+            let is_within_limit = if self.is_buy { level.p <= price_limit } else { level.p >= price_limit };
+            if is_within_limit {
+                (level.q, false)
+            } else {
+                (FPDecimal::ZERO, true)
+            }
+        })
+    }
+
+    pub fn max_quantity_within_price(&self, price_limit: FPDecimal) -> FPDecimal {
+        self.fill_within_price(price_limit).map_or(FPDecimal::ZERO, |fill| fill.quantity)
+    }
+
+    /// `take` returns the quantity to match at the given level and whether the walk is done.
+    /// Levels without a positive price and quantity are skipped.
+    fn walk(&self, mut take: impl FnMut(&PriceLevel) -> (FPDecimal, bool)) -> Option<OrderbookFill> {
+        let mut quantity = FPDecimal::ZERO;
+        let mut notional = FPDecimal::ZERO;
+        let mut best_price = None;
+        let mut worst_price = FPDecimal::ZERO;
+        let mut is_complete = false;
+
+        let levels = self.levels.iter().filter(|level| level.p > FPDecimal::ZERO && level.q > FPDecimal::ZERO);
+        for level in levels {
+            best_price.get_or_insert(level.p);
+            let (matched, is_done) = take(level);
+            if !matched.is_zero() {
+                quantity += matched;
+                notional += matched * level.p;
+                worst_price = level.p;
+            }
+            if is_done {
+                is_complete = true;
+                break;
+            }
+        }
+
+        let best_price = match best_price {
+            Some(best_price) if !quantity.is_zero() => best_price,
+            _ => return None,
+        };
+        let average_price = notional / quantity;
+
+        Some(OrderbookFill {
+            quantity,
+            notional,
+            average_price,
+            best_price,
+            worst_price,
+            slippage: (average_price - best_price).abs() / best_price,
+            fee: notional * self.fee_rate,
+            is_complete,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::testing_helpers::create_mock_spot_market;
+
+    fn level(p: &str, q: &str) -> PriceLevel {
+        PriceLevel::new(FPDecimal::must_from_str(p), FPDecimal::must_from_str(q))
+    }
+
+    fn orderbook() -> QueryOrderbookResponse {
+        QueryOrderbookResponse {
+            buys_price_level: vec![level("9", "10"), level("8", "20")],
+            sells_price_level: vec![level("10", "5"), level("11", "5"), level("12", "10")],
+        }
+    }
+
+    #[test]
+    fn fills_quantity_across_levels() {
+        let orderbook = orderbook();
+        let fill = OrderbookSimulator::buy(&orderbook)
+            .with_fee_rate(FPDecimal::must_from_str("0.001"))
+            .fill(FillTarget::Quantity(FPDecimal::must_from_str("12")))
+            .unwrap();
+
+        assert_eq!(fill.quantity, FPDecimal::must_from_str("12"));
+        assert_eq!(fill.notional, FPDecimal::must_from_str("129"));
+        assert_eq!(fill.average_price, FPDecimal::must_from_str("10.75"));
+        assert_eq!(fill.best_price, FPDecimal::must_from_str("10"));
+        assert_eq!(fill.worst_price, FPDecimal::must_from_str("12"));
+        assert_eq!(fill.slippage, FPDecimal::must_from_str("0.075"));
+        assert_eq!(fill.fee, FPDecimal::must_from_str("0.129"));
+        assert_eq!(fill.quote_amount(true), FPDecimal::must_from_str("129.129"));
+        assert!(fill.is_complete);
+
+        let fill = OrderbookSimulator::sell(&orderbook)
+            .fill(FillTarget::Quantity(FPDecimal::must_from_str("50")))
+            .unwrap();
+        assert_eq!(fill.quantity, FPDecimal::must_from_str("30"));
+        assert_eq!(fill.worst_price, FPDecimal::must_from_str("8"));
+        assert!(!fill.is_complete);
+    }
+
+    #[test]
+    fn fills_notional_across_levels() {
+        let orderbook = orderbook();
+        let fill = OrderbookSimulator::buy(&orderbook)
+            .fill(FillTarget::Notional(FPDecimal::must_from_str("83")))
+            .unwrap();
+
+        assert_eq!(fill.quantity, FPDecimal::must_from_str("8"));
+        assert_eq!(fill.notional, FPDecimal::must_from_str("83"));
+        assert_eq!(fill.worst_price, FPDecimal::must_from_str("11"));
+        assert!(fill.is_complete);
+
+        assert_eq!(OrderbookSimulator::buy(&orderbook).fill(FillTarget::Notional(FPDecimal::ZERO)), None);
+        assert_eq!(OrderbookSimulator::new(&[], true).fill(FillTarget::Quantity(FPDecimal::ONE)), None);
+    }
+
+    #[test]
+    fn ignores_non_positive_targets_and_levels() {
+        let orderbook = orderbook();
+        let simulator = OrderbookSimulator::buy(&orderbook);
+        assert_eq!(simulator.fill(FillTarget::Quantity(FPDecimal::must_from_str("-1"))), None);
+        assert_eq!(simulator.fill(FillTarget::Notional(FPDecimal::must_from_str("-10"))), None);
+
+        let levels = [level("0", "5"), level("10", "0"), level("-1", "5"), level("11", "5")];
+        let fill = OrderbookSimulator::new(&levels, true)
+            .fill(FillTarget::Notional(FPDecimal::must_from_str("22")))
+            .unwrap();
+        assert_eq!(fill.quantity, FPDecimal::must_from_str("2"));
+        assert_eq!(fill.best_price, FPDecimal::must_from_str("11"));
+        assert_eq!(fill.slippage, FPDecimal::ZERO);
+
+        assert_eq!(
+            OrderbookSimulator::new(&levels[..3], true).fill(FillTarget::Quantity(FPDecimal::ONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_max_quantity_within_price_limit() {
+        let orderbook = orderbook();
+
+        assert_eq!(
+            OrderbookSimulator::buy(&orderbook).max_quantity_within_price(FPDecimal::must_from_str("11.5")),
+            FPDecimal::must_from_str("10")
+        );
+        assert_eq!(
+            OrderbookSimulator::sell(&orderbook).max_quantity_within_price(FPDecimal::must_from_str("8")),
+            FPDecimal::must_from_str("30")
+        );
+        assert_eq!(
+            OrderbookSimulator::buy(&orderbook).max_quantity_within_price(FPDecimal::must_from_str("9")),
+            FPDecimal::ZERO
+        );
+    }
+
+    #[test]
+    fn applies_atomic_fee_multiplier() {
+        let orderbook = orderbook();
+        let mut market = create_mock_spot_market("inj", 0);
+        market.taker_fee_rate = FPDecimal::must_from_str("0.001");
+        let multiplier = QueryMarketAtomicExecutionFeeMultiplierResponse {
+            multiplier: FPDecimal::must_from_str("2.5"),
+        };
+
+        let fill = OrderbookSimulator::buy(&orderbook)
+            .with_taker_fee(&market, Some(&multiplier))
+            .fill(FillTarget::Quantity(FPDecimal::must_from_str("5")))
+            .unwrap();
+
+        assert_eq!(fill.fee, FPDecimal::must_from_str("0.125"));
+    }
+}
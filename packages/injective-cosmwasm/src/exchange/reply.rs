@@ -0,0 +1,381 @@
+use cosmwasm_std::{Reply, StdError, SubMsgResponse};
+use injective_math::FPDecimal;
+use injective_std::types::injective::exchange::v1beta1 as exchange;
+use prost::Message;
+use thiserror::Error;
+
+use crate::exchange::order::{dec_from_proto, non_empty};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ReplyParseError {
+    #[error("Failure response from submsg: {0}")]
+    SubMsgFailure(String),
+
+    #[error("Sub-message response contains neither msg_responses nor data")]
+    MissingResponse,
+
+    #[error("Unexpected sub-message response type: expected one of {expected:?}, got {actual}")]
+    UnexpectedTypeUrl { expected: Vec<String>, actual: String },
+
+    #[error("Failed to decode {type_url}: {err}")]
+    Decode { type_url: String, err: String },
+
+    #[error("Response {0} does not contain trade results")]
+    MissingResults(String),
+
+    #[error("Invalid decimal in field {field}: {value}")]
+    InvalidDecimal { field: String, value: String },
+}
+
+impl From<ReplyParseError> for StdError {
+    fn from(err: ReplyParseError) -> Self {
+        StdError::parse_err("exchange reply", err.to_string())
+    }
+}
+
+/// Typed view of an exchange `Msg*Response` which can be decoded from a sub-message reply
+pub trait ExchangeReply: Sized {
+    /// Protobuf type urls of the responses this type can be decoded from
+    const TYPE_URLS: &'static [&'static str];
+
+    fn decode_response(type_url: &str, bytes: &[u8]) -> Result<Self, ReplyParseError>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpotMarketOrderReply {
+    pub order_hash: String,
+    pub cid: Option<String>,
+    pub quantity: FPDecimal,
+    pub price: FPDecimal,
+    pub fee: FPDecimal,
+}
+
+/// Also used for binary options market orders, which share the response layout
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivativeMarketOrderReply {
+    pub order_hash: String,
+    pub cid: Option<String>,
+    pub quantity: FPDecimal,
+    pub price: FPDecimal,
+    pub fee: FPDecimal,
+    pub payout: FPDecimal,
+}
+
+/// Spot, derivative and binary options limit orders all respond with the order hash and cid
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LimitOrderReply {
+    pub order_hash: String,
+    pub cid: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchUpdateOrdersReply {
+    pub spot_cancel_success: Vec<bool>,
+    pub derivative_cancel_success: Vec<bool>,
+    pub binary_options_cancel_success: Vec<bool>,
+    pub spot_order_hashes: Vec<String>,
+    pub derivative_order_hashes: Vec<String>,
+    pub binary_options_order_hashes: Vec<String>,
+    pub created_spot_orders_cids: Vec<String>,
+    pub failed_spot_orders_cids: Vec<String>,
+    pub created_derivative_orders_cids: Vec<String>,
+    pub failed_derivative_orders_cids: Vec<String>,
+    pub created_binary_options_orders_cids: Vec<String>,
+    pub failed_binary_options_orders_cids: Vec<String>,
+}
+
+impl ExchangeReply for SpotMarketOrderReply {
+    const TYPE_URLS: &'static [&'static str] = &["/injective.exchange.v1beta1.MsgCreateSpotMarketOrderResponse"];
+
+    fn decode_response(type_url: &str, bytes: &[u8]) -> Result<Self, ReplyParseError> {
+        let response: exchange::MsgCreateSpotMarketOrderResponse = decode(type_url, bytes)?;
+        let results = response.results.ok_or_else(|| ReplyParseError::MissingResults(type_url.to_string()))?;
+
+        Ok(SpotMarketOrderReply {
+            order_hash: response.order_hash,
+            cid: non_empty(response.cid),
+            quantity: parse_proto_dec("quantity", &results.quantity)?,
+            price: parse_proto_dec("price", &results.price)?,
+            fee: parse_proto_dec("fee", &results.fee)?,
+        })
+    }
+}
+
+impl ExchangeReply for DerivativeMarketOrderReply {
+    const TYPE_URLS: &'static [&'static str] = &[
+        "/injective.exchange.v1beta1.MsgCreateDerivativeMarketOrderResponse",
+        "/injective.exchange.v1beta1.MsgCreateBinaryOptionsMarketOrderResponse",
+    ];
+
+    fn decode_response(type_url: &str, bytes: &[u8]) -> Result<Self, ReplyParseError> {
+        let response: exchange::MsgCreateDerivativeMarketOrderResponse = decode(type_url, bytes)?;
+        let results = response.results.ok_or_else(|| ReplyParseError::MissingResults(type_url.to_string()))?;
+
+        Ok(DerivativeMarketOrderReply {
+            order_hash: response.order_hash,
+            cid: non_empty(response.cid),
+            quantity: parse_proto_dec("quantity", &results.quantity)?,
+            price: parse_proto_dec("price", &results.price)?,
+            fee: parse_proto_dec("fee", &results.fee)?,
+            payout: parse_proto_dec("payout", &results.payout)?,
+        })
+    }
+}
+
+impl ExchangeReply for LimitOrderReply {
+    const TYPE_URLS: &'static [&'static str] = &[
+        "/injective.exchange.v1beta1.MsgCreateSpotLimitOrderResponse",
+        "/injective.exchange.v1beta1.MsgCreateDerivativeLimitOrderResponse",
+        "/injective.exchange.v1beta1.MsgCreateBinaryOptionsLimitOrderResponse",
+    ];
+
+    fn decode_response(type_url: &str, bytes: &[u8]) -> Result<Self, ReplyParseError> {
+        let response: exchange::MsgCreateSpotLimitOrderResponse = decode(type_url, bytes)?;
+
+        Ok(LimitOrderReply {
+            order_hash: response.order_hash,
+            cid: non_empty(response.cid),
+        })
+    }
+}
+
+impl ExchangeReply for BatchUpdateOrdersReply {
+    const TYPE_URLS: &'static [&'static str] = &["/injective.exchange.v1beta1.MsgBatchUpdateOrdersResponse"];
+
+    fn decode_response(type_url: &str, bytes: &[u8]) -> Result<Self, ReplyParseError> {
+        let response: exchange::MsgBatchUpdateOrdersResponse = decode(type_url, bytes)?;
+
+        Ok(BatchUpdateOrdersReply {
+            spot_cancel_success: response.spot_cancel_success,
+            derivative_cancel_success: response.derivative_cancel_success,
+            binary_options_cancel_success: response.binary_options_cancel_success,
+            spot_order_hashes: response.spot_order_hashes,
+            derivative_order_hashes: response.derivative_order_hashes,
+            binary_options_order_hashes: response.binary_options_order_hashes,
+            created_spot_orders_cids: response.created_spot_orders_cids,
+            failed_spot_orders_cids: response.failed_spot_orders_cids,
+            created_derivative_orders_cids: response.created_derivative_orders_cids,
+            failed_derivative_orders_cids: response.failed_derivative_orders_cids,
+            created_binary_options_orders_cids: response.created_binary_options_orders_cids,
+            failed_binary_options_orders_cids: response.failed_binary_options_orders_cids,
+        })
+    }
+}
+
+/// Decodes the response of a successful exchange sub-message from a `Reply`
+pub fn parse_reply<T: ExchangeReply>(reply: Reply) -> Result<T, ReplyParseError> {
+    let response = reply.result.into_result().map_err(ReplyParseError::SubMsgFailure)?;
+    parse_sub_msg_response(&response)
+}
+
+/// Decodes an exchange response from `msg_responses`, falling back to the legacy `data` field
+#[allow(deprecated)]
+pub fn parse_sub_msg_response<T: ExchangeReply>(response: &SubMsgResponse) -> Result<T, ReplyParseError> {
+    if let Some(msg_response) = response.msg_responses.iter().find(|r| T::TYPE_URLS.contains(&r.type_url.as_str())) {
+        return T::decode_response(&msg_response.type_url, msg_response.value.as_slice());
+    }
+
+    if let Some(msg_response) = response.msg_responses.first() {
+        return Err(ReplyParseError::UnexpectedTypeUrl {
+            expected: T::TYPE_URLS.iter().map(|url| url.to_string()).collect(),
+            actual: msg_response.type_url.to_owned(),
+        });
+    }
+
+    match &response.data {
+        Some(data) => T::decode_response(T::TYPE_URLS[0], data.as_slice()),
+        None => Err(ReplyParseError::MissingResponse),
+    }
+}
+
+fn decode<M: Message + Default>(type_url: &str, bytes: &[u8]) -> Result<M, ReplyParseError> {
+    M::decode(bytes).map_err(|err| ReplyParseError::Decode {
+        type_url: type_url.to_string(),
+        err: err.to_string(),
+    })
+}
+
+fn parse_proto_dec(field: &str, value: &str) -> Result<FPDecimal, ReplyParseError> {
+    dec_from_proto(value).map_err(|_| ReplyParseError::InvalidDecimal {
+        field: field.to_string(),
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Binary, MsgResponse, Reply, SubMsgResponse, SubMsgResult};
+    use injective_math::FPDecimal;
+    use injective_std::types::injective::exchange::v1beta1 as exchange;
+    use prost::Message;
+
+    use super::*;
+
+    const SPOT_MARKET_ORDER_RESPONSE: &str = "CkIweGRkNzI5MmY2ODcwMzIwOTc2YTUxYTUwODBiMGQ2NDU5M2NhZjE3OWViM2YxOTNjZWVlZGFiNGVhNWUxNDljZWISQwoTODAwMDAwMDAwMDAwMDAwMDAwMBIWMTAwMDAwMDAwMDAwMDAwMDAwMDAwMBoUMzYwMDAwMDAwMDAwMDAwMDAwMDA=";
+
+    #[allow(deprecated)]
+    fn reply_with(msg_responses: Vec<MsgResponse>, data: Option<Binary>) -> Reply {
+        Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data,
+                msg_responses,
+            }),
+            payload: Binary::default(),
+            gas_used: 0,
+        }
+    }
+
+    #[test]
+    fn parses_spot_market_order_from_msg_responses() {
+        let reply = reply_with(
+            vec![MsgResponse {
+                type_url: "/injective.exchange.v1beta1.MsgCreateSpotMarketOrderResponse".to_string(),
+                value: Binary::from_base64(SPOT_MARKET_ORDER_RESPONSE).unwrap(),
+            }],
+            None,
+        );
+
+        let parsed: SpotMarketOrderReply = parse_reply(reply).unwrap();
+        assert_eq!(parsed.order_hash, "0xdd7292f6870320976a51a5080b0d64593caf179eb3f193ceeedab4ea5e149ceb");
+        assert_eq!(parsed.cid, None);
+        assert_eq!(parsed.quantity, FPDecimal::from(8u128));
+        assert_eq!(parsed.price, FPDecimal::from(1000u128));
+        assert_eq!(parsed.fee, FPDecimal::from(36u128));
+    }
+
+    #[test]
+    fn parses_spot_market_order_from_legacy_data() {
+        let reply = reply_with(vec![], Some(Binary::from_base64(SPOT_MARKET_ORDER_RESPONSE).unwrap()));
+
+        let parsed: SpotMarketOrderReply = parse_reply(reply).unwrap();
+        assert_eq!(parsed.quantity, FPDecimal::from(8u128));
+    }
+
+    #[test]
+    fn parses_derivative_market_order() {
+        let response = exchange::MsgCreateDerivativeMarketOrderResponse {
+            order_hash: "0x01".to_string(),
+            results: Some(exchange::DerivativeMarketOrderResults {
+                quantity: "1500000000000000000".to_string(),
+                price: "20000000000000000000".to_string(),
+                fee: "30000000000000000".to_string(),
+                position_delta: None,
+                payout: "".to_string(),
+            }),
+            cid: "my-cid".to_string(),
+        };
+        let reply = reply_with(
+            vec![MsgResponse {
+                type_url: "/injective.exchange.v1beta1.MsgCreateDerivativeMarketOrderResponse".to_string(),
+                value: Binary::from(response.encode_to_vec()),
+            }],
+            None,
+        );
+
+        let parsed: DerivativeMarketOrderReply = parse_reply(reply).unwrap();
+        assert_eq!(parsed.cid, Some("my-cid".to_string()));
+        assert_eq!(parsed.quantity, FPDecimal::must_from_str("1.5"));
+        assert_eq!(parsed.price, FPDecimal::from(20u128));
+        assert_eq!(parsed.fee, FPDecimal::must_from_str("0.03"));
+        assert_eq!(parsed.payout, FPDecimal::ZERO);
+    }
+
+    #[test]
+    fn parses_limit_order_and_batch_update() {
+        let limit = exchange::MsgCreateDerivativeLimitOrderResponse {
+            order_hash: "0x02".to_string(),
+            cid: "".to_string(),
+        };
+        let batch = exchange::MsgBatchUpdateOrdersResponse {
+            spot_cancel_success: vec![true, false],
+            spot_order_hashes: vec!["0x03".to_string()],
+            created_spot_orders_cids: vec!["a".to_string()],
+            ..Default::default()
+        };
+        let response = SubMsgResponse {
+            events: vec![],
+            #[allow(deprecated)]
+            data: None,
+            msg_responses: vec![
+                MsgResponse {
+                    type_url: "/injective.exchange.v1beta1.MsgCreateDerivativeLimitOrderResponse".to_string(),
+                    value: Binary::from(limit.encode_to_vec()),
+                },
+                MsgResponse {
+                    type_url: "/injective.exchange.v1beta1.MsgBatchUpdateOrdersResponse".to_string(),
+                    value: Binary::from(batch.encode_to_vec()),
+                },
+            ],
+        };
+
+        let parsed_limit: LimitOrderReply = parse_sub_msg_response(&response).unwrap();
+        assert_eq!(
+            parsed_limit,
+            LimitOrderReply {
+                order_hash: "0x02".to_string(),
+                cid: None
+            }
+        );
+
+        let parsed_batch: BatchUpdateOrdersReply = parse_sub_msg_response(&response).unwrap();
+        assert_eq!(parsed_batch.spot_cancel_success, vec![true, false]);
+        assert_eq!(parsed_batch.spot_order_hashes, vec!["0x03".to_string()]);
+        assert_eq!(parsed_batch.created_spot_orders_cids, vec!["a".to_string()]);
+        assert!(parsed_batch.derivative_order_hashes.is_empty());
+    }
+
+    #[test]
+    fn reports_errors() {
+        let failed = Reply {
+            id: 1,
+            result: SubMsgResult::Err("out of funds".to_string()),
+            payload: Binary::default(),
+            gas_used: 0,
+        };
+        assert_eq!(
+            parse_reply::<SpotMarketOrderReply>(failed).unwrap_err(),
+            ReplyParseError::SubMsgFailure("out of funds".to_string())
+        );
+
+        assert_eq!(
+            parse_reply::<SpotMarketOrderReply>(reply_with(vec![], None)).unwrap_err(),
+            ReplyParseError::MissingResponse
+        );
+
+        let wrong_type = reply_with(
+            vec![MsgResponse {
+                type_url: "/injective.exchange.v1beta1.MsgDepositResponse".to_string(),
+                value: Binary::default(),
+            }],
+            None,
+        );
+        assert!(matches!(
+            parse_reply::<SpotMarketOrderReply>(wrong_type).unwrap_err(),
+            ReplyParseError::UnexpectedTypeUrl { .. }
+        ));
+
+        let no_results = exchange::MsgCreateSpotMarketOrderResponse {
+            order_hash: "0x04".to_string(),
+            results: None,
+            cid: "".to_string(),
+        };
+        assert_eq!(
+            parse_reply::<SpotMarketOrderReply>(reply_with(vec![], Some(Binary::from(no_results.encode_to_vec())))).unwrap_err(),
+            ReplyParseError::MissingResults("/injective.exchange.v1beta1.MsgCreateSpotMarketOrderResponse".to_string())
+        );
+
+        let garbage = reply_with(
+            vec![MsgResponse {
+                type_url: "/injective.exchange.v1beta1.MsgCreateSpotMarketOrderResponse".to_string(),
+                value: Binary::from(vec![0xff, 0xff, 0xff]),
+            }],
+            None,
+        );
+        assert!(matches!(
+            parse_reply::<SpotMarketOrderReply>(garbage).unwrap_err(),
+            ReplyParseError::Decode { .. }
+        ));
+    }
+}
@@ -4,11 +4,13 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::exchange::{
-    derivative::{EffectivePosition, Position, TrimmedDerivativeLimitOrder},
+    binary_options_market::BinaryOptionsMarket,
+    derivative::{DerivativePosition, EffectivePosition, Position, TrimmedDerivativeConditionalOrder, TrimmedDerivativeLimitOrder},
     derivative_market::{FullDerivativeMarket, PerpetualMarketFunding, PerpetualMarketInfo},
+    rewards::{CampaignRewardPool, TradingRewardCampaignInfo},
     spot::TrimmedSpotLimitOrder,
     spot_market::SpotMarket,
-    types::{DenomDecimals, Deposit, MarketVolume, Params, PriceLevel, VolumeByType},
+    types::{DenomDecimals, Deposit, FeeDiscountSchedule, FeeDiscountTierInfo, FeeDiscountTierTTL, MarketVolume, Params, PriceLevel, VolumeByType},
 };
 use crate::oracle::volatility::{MetadataStatistics, TradeRecord};
 
@@ -27,6 +29,12 @@ pub struct SubaccountEffectivePositionInMarketResponse {
     pub state: Option<EffectivePosition>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SubaccountPositionsResponse {
+    #[serde(default)]
+    pub state: Vec<DerivativePosition>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct SubaccountPositionInMarketResponse {
     pub state: Option<Position>,
@@ -47,21 +55,64 @@ pub struct TraderDerivativeOrdersResponse {
     pub orders: Option<Vec<TrimmedDerivativeLimitOrder>>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TraderDerivativeConditionalOrdersResponse {
+    #[serde(default)]
+    pub orders: Vec<TrimmedDerivativeConditionalOrder>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TraderSpotOrdersResponse {
     pub orders: Option<Vec<TrimmedSpotLimitOrder>>,
 }
 
+/// Resting spot orders among the queried hashes, hashes of filled or cancelled orders are left out
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpotOrdersByHashesResponse {
+    #[serde(default)]
+    pub orders: Vec<TrimmedSpotLimitOrder>,
+}
+
+/// Resting derivative orders among the queried hashes, hashes of filled or cancelled orders are left out
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DerivativeOrdersByHashesResponse {
+    #[serde(default)]
+    pub orders: Vec<TrimmedDerivativeLimitOrder>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct DerivativeMarketResponse {
     pub market: Option<FullDerivativeMarket>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DerivativeMarketsResponse {
+    #[serde(default)]
+    pub markets: Vec<FullDerivativeMarket>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct SpotMarketResponse {
     pub market: Option<SpotMarket>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpotMarketsResponse {
+    #[serde(default)]
+    pub markets: Vec<SpotMarket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BinaryOptionsMarketResponse {
+    pub market: Option<BinaryOptionsMarket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BinaryOptionsMarketsResponse {
+    #[serde(default)]
+    pub markets: Vec<BinaryOptionsMarket>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct MarketMidPriceAndTOBResponse {
     pub mid_price: Option<FPDecimal>,
@@ -125,3 +176,43 @@ pub struct QueryDenomDecimalsResponse {
 pub struct QueryMarketAtomicExecutionFeeMultiplierResponse {
     pub multiplier: FPDecimal,
 }
+
+/// Response to query for the fee discount schedule
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeDiscountScheduleResponse {
+    pub fee_discount_schedule: Option<FeeDiscountSchedule>,
+}
+
+/// Response to query for the fee discount tier of an account, `account_info` holds the discount rates of the current tier
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeDiscountAccountInfoResponse {
+    pub tier_level: u64,
+    pub account_info: Option<FeeDiscountTierInfo>,
+    pub account_ttl: Option<FeeDiscountTierTTL>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IsOptedOutOfRewardsResponse {
+    #[serde(default)]
+    pub is_opted_out: bool,
+}
+
+/// Reward points of the queried accounts, in the order the accounts were passed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeRewardPointsResponse {
+    #[serde(default)]
+    pub account_trade_reward_points: Vec<FPDecimal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeRewardCampaignResponse {
+    pub trading_reward_campaign_info: Option<TradingRewardCampaignInfo>,
+    #[serde(default)]
+    pub trading_reward_pool_campaign_schedule: Vec<CampaignRewardPool>,
+    #[serde(default)]
+    pub total_trade_reward_points: FPDecimal,
+    #[serde(default)]
+    pub pending_trading_reward_pool_campaign_schedule: Vec<CampaignRewardPool>,
+    #[serde(default)]
+    pub pending_total_trade_reward_points: Vec<FPDecimal>,
+}
@@ -0,0 +1,108 @@
+use cosmwasm_std::{Coin, Uint128};
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::{response::TradeRewardCampaignResponse, types::MarketId};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PointsMultiplier {
+    pub maker_points_multiplier: FPDecimal,
+    pub taker_points_multiplier: FPDecimal,
+}
+
+/// Markets whose trades earn boosted reward points, multipliers are in the same order as the market ids
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradingRewardCampaignBoostInfo {
+    #[serde(default, alias = "boosted_spot_marketIDs")]
+    pub boosted_spot_market_ids: Vec<MarketId>,
+    #[serde(default)]
+    pub spot_market_multipliers: Vec<PointsMultiplier>,
+    #[serde(default, alias = "boosted_derivative_marketIDs")]
+    pub boosted_derivative_market_ids: Vec<MarketId>,
+    #[serde(default)]
+    pub derivative_market_multipliers: Vec<PointsMultiplier>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradingRewardCampaignInfo {
+    pub campaign_duration_seconds: i64,
+    /// Quote denoms whose trading fees count towards reward points
+    #[serde(default)]
+    pub quote_denoms: Vec<String>,
+    pub trading_reward_boost_info: Option<TradingRewardCampaignBoostInfo>,
+    #[serde(default, alias = "disqualified_marketIDs")]
+    pub disqualified_market_ids: Vec<MarketId>,
+}
+
+/// Rewards disbursed among all reward points earned in the campaign starting at `start_timestamp`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CampaignRewardPool {
+    pub start_timestamp: i64,
+    #[serde(default)]
+    pub max_campaign_rewards: Vec<Coin>,
+}
+
+impl TradeRewardCampaignResponse {
+    /// Pool of the running campaign, the first entry of the schedule
+    pub fn current_reward_pool(&self) -> Option<&CampaignRewardPool> {
+        self.trading_reward_pool_campaign_schedule.first()
+    }
+
+    /// Share of the current pool's rewards for `points` reward points, assuming the total points stay unchanged
+    pub fn estimate_rewards(&self, points: FPDecimal) -> Vec<Coin> {
+        let Some(pool) = self.current_reward_pool() else {
+            return vec![];
+        };
+        if self.total_trade_reward_points.is_zero() || points.is_zero() {
+            return vec![];
+        }
+
+        let share = (points / self.total_trade_reward_points).minimum(&FPDecimal::ONE);
+        pool.max_campaign_rewards
+            .iter()
+            .map(|reward| Coin::new(Uint128::from(FPDecimal::from(reward.amount) * share), reward.denom.clone()))
+            .filter(|reward| !reward.amount.is_zero())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn campaign() -> TradeRewardCampaignResponse {
+        TradeRewardCampaignResponse {
+            trading_reward_campaign_info: Some(TradingRewardCampaignInfo {
+                campaign_duration_seconds: 2419200,
+                quote_denoms: vec!["usdt".to_string()],
+                trading_reward_boost_info: None,
+                disqualified_market_ids: vec![],
+            }),
+            trading_reward_pool_campaign_schedule: vec![CampaignRewardPool {
+                start_timestamp: 1700000000,
+                max_campaign_rewards: vec![Coin::new(1_000_000u128, "inj"), Coin::new(3u128, "usdt")],
+            }],
+            total_trade_reward_points: FPDecimal::must_from_str("400"),
+            pending_trading_reward_pool_campaign_schedule: vec![],
+            pending_total_trade_reward_points: vec![],
+        }
+    }
+
+    #[test]
+    fn estimates_share_of_current_reward_pool() {
+        let campaign = campaign();
+
+        assert_eq!(
+            campaign.estimate_rewards(FPDecimal::must_from_str("100")),
+            vec![Coin::new(250_000u128, "inj")]
+        );
+        assert_eq!(campaign.estimate_rewards(FPDecimal::ZERO), vec![]);
+
+        let finished = TradeRewardCampaignResponse {
+            trading_reward_pool_campaign_schedule: vec![],
+            ..campaign
+        };
+        assert_eq!(finished.estimate_rewards(FPDecimal::must_from_str("100")), vec![]);
+    }
+}
@@ -0,0 +1,257 @@
+use injective_math::FPDecimal;
+
+use crate::exchange::{
+    derivative::{DerivativeOrder, Position},
+    derivative_market::{DerivativeMarket, PerpetualMarketFunding},
+    validation::OrderValidationError,
+};
+
+impl Position {
+    /// Margin of the position after settling funding accrued since entry, expiry futures markets pass `None`
+    pub fn margin_with_funding(&self, funding: Option<&PerpetualMarketFunding>) -> FPDecimal {
+        match funding {
+            Some(funding) => self.get_position_value_with_funding(self.entry_price, funding.cumulative_funding),
+            None => self.margin,
+        }
+    }
+
+    /// Mark price at which the position value falls to the maintenance margin requirement.
+    /// Can be negative for longs whose margin covers more than the position notional.
+    pub fn liquidation_price(&self, market: &DerivativeMarket, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        if self.quantity.is_zero() {
+            return None;
+        }
+
+        let unit_margin = self.margin_with_funding(funding) / self.quantity;
+        let liquidation_price = if self.isLong {
+            (self.entry_price - unit_margin) / (FPDecimal::ONE - market.maintenance_margin_ratio)
+        } else {
+            (self.entry_price + unit_margin) / (FPDecimal::ONE + market.maintenance_margin_ratio)
+        };
+
+        Some(liquidation_price)
+    }
+
+    /// Mark price at which the position value falls to zero
+    pub fn bankruptcy_price(&self, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        if self.quantity.is_zero() {
+            return None;
+        }
+
+        let unit_margin = self.margin_with_funding(funding) / self.quantity;
+        let bankruptcy_price = if self.isLong {
+            self.entry_price - unit_margin
+        } else {
+            self.entry_price + unit_margin
+        };
+
+        Some(bankruptcy_price)
+    }
+
+    pub fn maintenance_margin_requirement(&self, market: &DerivativeMarket, mark_price: FPDecimal) -> FPDecimal {
+        self.quantity * mark_price * market.maintenance_margin_ratio
+    }
+
+    pub fn is_liquidatable(&self, market: &DerivativeMarket, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> bool {
+        self.value_at(mark_price, funding) < self.maintenance_margin_requirement(market, mark_price)
+    }
+
+    /// Notional at mark price over position value, `None` if the position value is not positive
+    pub fn effective_leverage(&self, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        let value = self.value_at(mark_price, funding);
+        if value.is_zero() || value.is_negative() {
+            return None;
+        }
+
+        Some(self.quantity * mark_price / value)
+    }
+
+    fn value_at(&self, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> FPDecimal {
+        match funding {
+            Some(funding) => self.get_position_value_with_funding(mark_price, funding.cumulative_funding),
+            None => self.get_position_value_without_funding(mark_price),
+        }
+    }
+}
+
+/// Margin the exchange requires to open a position of `quantity` at `price`. Besides covering the initial margin
+/// ratio of the order notional, the position must also satisfy the initial margin ratio when valued at mark price.
+pub fn initial_margin_requirement(
+    market: &DerivativeMarket,
+    price: FPDecimal,
+    quantity: FPDecimal,
+    is_buy: bool,
+    mark_price: FPDecimal,
+) -> FPDecimal {
+    let notional_requirement = quantity * price * market.initial_margin_ratio;
+    let mark_price_requirement = if is_buy {
+        quantity * (market.initial_margin_ratio * mark_price - mark_price + price)
+    } else {
+        quantity * (market.initial_margin_ratio * mark_price + mark_price - price)
+    };
+
+    notional_requirement.maximum(&mark_price_requirement)
+}
+
+/// Checks the margin of an order opening or increasing a position, reduce-only orders carry no margin and are not covered
+pub fn check_initial_margin(market: &DerivativeMarket, order: &DerivativeOrder, mark_price: FPDecimal) -> Result<(), OrderValidationError> {
+    let required = initial_margin_requirement(
+        market,
+        order.order_info.price,
+        order.order_info.quantity,
+        order.order_type.is_buy(),
+        mark_price,
+    );
+
+    if order.margin < required {
+        return Err(OrderValidationError::InsufficientMargin {
+            margin: order.margin,
+            required,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use super::*;
+    use crate::exchange::order::OrderType;
+    use crate::exchange::types::{MarketId, SubaccountId};
+    use crate::oracle::types::OracleType;
+    use crate::test_helpers::testing_helpers::TEST_MARKET_ID_1;
+    use crate::MarketStatus;
+
+    fn market() -> DerivativeMarket {
+        DerivativeMarket {
+            ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "inj".to_string(),
+            oracle_quote: "usdt".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            oracle_scale_factor: 6,
+            quote_denom: "usdt".to_string(),
+            market_id: MarketId::unchecked(TEST_MARKET_ID_1),
+            initial_margin_ratio: FPDecimal::must_from_str("0.1"),
+            maintenance_margin_ratio: FPDecimal::must_from_str("0.05"),
+            maker_fee_rate: FPDecimal::must_from_str("0.001"),
+            taker_fee_rate: FPDecimal::must_from_str("0.002"),
+            isPerpetual: true,
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.1"),
+            min_notional: FPDecimal::ZERO,
+            relayer_fee_share_rate: None,
+        }
+    }
+
+    fn position(is_long: bool) -> Position {
+        Position {
+            isLong: is_long,
+            quantity: FPDecimal::must_from_str("10"),
+            entry_price: FPDecimal::must_from_str("100"),
+            margin: FPDecimal::must_from_str("200"),
+            cumulative_funding_entry: FPDecimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn computes_liquidation_and_bankruptcy_prices() {
+        let market = market();
+
+        let long = position(true);
+        assert_eq!(long.bankruptcy_price(None), Some(FPDecimal::must_from_str("80")));
+        assert_eq!(
+            long.liquidation_price(&market, None).unwrap(),
+            FPDecimal::must_from_str("84.210526315789473684")
+        );
+
+        let short = position(false);
+        assert_eq!(short.bankruptcy_price(None), Some(FPDecimal::must_from_str("120")));
+        assert_eq!(
+            short.liquidation_price(&market, None).unwrap(),
+            FPDecimal::must_from_str("114.285714285714285714")
+        );
+
+        let liquidation_price = long.liquidation_price(&market, None).unwrap();
+        assert!(!long.is_liquidatable(&market, liquidation_price + FPDecimal::must_from_str("0.01"), None));
+        assert!(long.is_liquidatable(&market, liquidation_price - FPDecimal::must_from_str("0.01"), None));
+
+        let empty = Position {
+            quantity: FPDecimal::ZERO,
+            ..position(true)
+        };
+        assert_eq!(empty.liquidation_price(&market, None), None);
+    }
+
+    #[test]
+    fn accounts_for_unrealized_funding() {
+        let market = market();
+        let funding = PerpetualMarketFunding {
+            cumulative_funding: FPDecimal::must_from_str("2"),
+            cumulative_price: FPDecimal::ZERO,
+            last_timestamp: 0,
+        };
+
+        // longs pay 2 per unit, shorts receive it
+        let long = position(true);
+        assert_eq!(long.margin_with_funding(Some(&funding)), FPDecimal::must_from_str("180"));
+        assert_eq!(long.bankruptcy_price(Some(&funding)), Some(FPDecimal::must_from_str("82")));
+
+        let short = position(false);
+        assert_eq!(short.margin_with_funding(Some(&funding)), FPDecimal::must_from_str("220"));
+        assert_eq!(short.bankruptcy_price(Some(&funding)), Some(FPDecimal::must_from_str("122")));
+        assert!(short.liquidation_price(&market, Some(&funding)).unwrap() > short.liquidation_price(&market, None).unwrap());
+    }
+
+    #[test]
+    fn computes_leverage_and_maintenance_requirement() {
+        let market = market();
+        let long = position(true);
+
+        assert_eq!(
+            long.effective_leverage(FPDecimal::must_from_str("100"), None),
+            Some(FPDecimal::must_from_str("5"))
+        );
+        assert_eq!(
+            long.effective_leverage(FPDecimal::must_from_str("90"), None),
+            Some(FPDecimal::must_from_str("9"))
+        );
+        assert_eq!(long.effective_leverage(FPDecimal::must_from_str("80"), None), None);
+        assert_eq!(
+            long.maintenance_margin_requirement(&market, FPDecimal::must_from_str("90")),
+            FPDecimal::must_from_str("45")
+        );
+    }
+
+    #[test]
+    fn checks_initial_margin() {
+        let market = market();
+        let subaccount_id = SubaccountId::unchecked("0x427aee334987c52fa7b567b2662bdbb68614e48c000000000000000000000001");
+        let mut order = DerivativeOrder::new(
+            FPDecimal::must_from_str("100"),
+            FPDecimal::must_from_str("10"),
+            FPDecimal::must_from_str("100"),
+            OrderType::Buy,
+            market.market_id.clone(),
+            subaccount_id,
+            None,
+            None,
+        );
+
+        assert_eq!(check_initial_margin(&market, &order, FPDecimal::must_from_str("100")), Ok(()));
+
+        // buying above mark price needs to cover the immediate loss at mark price
+        assert_eq!(
+            check_initial_margin(&market, &order, FPDecimal::must_from_str("95")),
+            Err(OrderValidationError::InsufficientMargin {
+                margin: FPDecimal::must_from_str("100"),
+                required: FPDecimal::must_from_str("145"),
+            })
+        );
+
+        order.margin = FPDecimal::must_from_str("99");
+        assert!(check_initial_margin(&market, &order, FPDecimal::must_from_str("110")).is_err());
+    }
+}
@@ -1,10 +1,11 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, StdError, StdResult};
+use injective_std::types::injective::exchange::v1beta1 as exchange;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use injective_math::FPDecimal;
 
-use crate::exchange::order::{GenericOrder, OrderInfo, OrderType};
+use crate::exchange::order::{optional_dec_from_proto, optional_dec_to_proto, GenericOrder, OrderInfo, OrderType};
 use crate::exchange::types::{MarketId, SubaccountId};
 
 use super::order::GenericTrimmedOrder;
@@ -124,6 +125,34 @@ impl GenericOrder for SpotOrder {
     }
 }
 
+impl From<SpotOrder> for exchange::SpotOrder {
+    fn from(order: SpotOrder) -> Self {
+        exchange::SpotOrder {
+            market_id: order.market_id.into(),
+            order_info: Some(order.order_info.into()),
+            order_type: order.order_type.into(),
+            trigger_price: optional_dec_to_proto(order.trigger_price),
+        }
+    }
+}
+
+impl TryFrom<exchange::SpotOrder> for SpotOrder {
+    type Error = StdError;
+
+    fn try_from(order: exchange::SpotOrder) -> StdResult<Self> {
+        let order_info = order
+            .order_info
+            .ok_or_else(|| StdError::generic_err("Missing order info in spot order"))?;
+
+        Ok(SpotOrder {
+            market_id: MarketId::new(order.market_id)?,
+            order_info: order_info.try_into()?,
+            order_type: order.order_type.try_into()?,
+            trigger_price: optional_dec_from_proto(&order.trigger_price)?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct ShortSpotOrder {
     pub market_id: MarketId,
@@ -243,6 +272,8 @@ pub struct TrimmedSpotLimitOrder {
     #[serde(default)]
     pub isBuy: bool,
     pub order_hash: String,
+    #[serde(default)]
+    pub cid: Option<String>,
 }
 
 impl GenericTrimmedOrder for TrimmedSpotLimitOrder {
@@ -265,6 +296,10 @@ impl GenericTrimmedOrder for TrimmedSpotLimitOrder {
     fn get_order_hash(&self) -> String {
         self.order_hash.to_owned()
     }
+
+    fn get_cid(&self) -> Option<&str> {
+        self.cid.as_deref().filter(|cid| !cid.is_empty())
+    }
 }
 
 #[allow(non_snake_case)]
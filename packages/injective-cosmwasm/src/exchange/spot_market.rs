@@ -3,9 +3,8 @@ use cosmwasm_std::StdResult;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use tiny_keccak::Keccak;
 
-use super::market::{GenericMarket, MarketStatus};
+use super::market::{hash_market_id, GenericMarket, MarketStatus};
 
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -56,15 +55,18 @@ impl GenericMarket for SpotMarket {
     fn min_quantity_tick_size(&self) -> FPDecimal {
         self.min_quantity_tick_size
     }
+
+    fn get_min_notional(&self) -> FPDecimal {
+        self.min_notional
+    }
+
+    fn get_relayer_fee_share_rate(&self) -> Option<FPDecimal> {
+        Some(self.relayer_fee_share_rate)
+    }
 }
 
 pub fn calculate_spot_market_id(base_denom: String, quote_denom: String) -> StdResult<MarketId> {
-    let mut hasher = Keccak::new_keccak256();
-    hasher.update((base_denom + &quote_denom).as_bytes());
-    let mut res = [0u8; 32];
-    hasher.finalize(&mut res);
-
-    MarketId::new(format!("0x{}", hex::encode(res)))
+    hash_market_id(&[&base_denom, &quote_denom])
 }
 
 #[cfg(test)]
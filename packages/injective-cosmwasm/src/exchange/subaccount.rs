@@ -1,22 +1,71 @@
 use std::str::FromStr;
 
-use cosmwasm_std::{Addr, Deps, StdResult};
+use cosmwasm_std::{Addr, CanonicalAddr, Deps, StdError, StdResult};
 use subtle_encoding::bech32;
 
 use ethereum_types::H160;
 
 use crate::{exchange::types::SubaccountId, InjectiveQueryWrapper};
 
+const INJ_PREFIX: &str = "inj";
+const NONCE_LENGTH: usize = 24;
+
+impl SubaccountId {
+    pub fn for_address(addr: &Addr, nonce: u32) -> StdResult<SubaccountId> {
+        try_address_to_subaccount_id(addr, nonce)
+    }
+
+    /// Nonce encoded in the trailing 12 bytes of the subaccount id
+    pub fn nonce(&self) -> StdResult<u32> {
+        let subaccount_id_str = self.as_str();
+        let hex_nonce = subaccount_id_str
+            .get(subaccount_id_str.len().saturating_sub(NONCE_LENGTH)..)
+            .ok_or_else(|| StdError::generic_err(format!("Invalid subaccount id {subaccount_id_str}")))?;
+
+        u128::from_str_radix(hex_nonce, 16)
+            .ok()
+            .and_then(|nonce| u32::try_from(nonce).ok())
+            .ok_or_else(|| StdError::generic_err(format!("Invalid subaccount nonce {hex_nonce}")))
+    }
+
+    /// Bech32 address with the given prefix of the account owning the subaccount
+    pub fn owner_address(&self, prefix: &str) -> StdResult<Addr> {
+        try_subaccount_id_to_address(self, prefix)
+    }
+
+    /// Compares the 20 address bytes, so owners with any bech32 prefix match
+    pub fn is_owned_by(&self, addr: &Addr) -> bool {
+        let owner = self.as_str().get(..42);
+        try_bech32_to_hex(addr).is_ok_and(|address| owner.is_some_and(|owner| owner.eq_ignore_ascii_case(&address)))
+    }
+}
+
+/// Subaccount ids of `addr` with nonces `0..count`, starting with the default subaccount
+pub fn subaccount_ids_for_address(addr: &Addr, count: u32) -> StdResult<Vec<SubaccountId>> {
+    let ethereum_address = try_bech32_to_hex(addr)?;
+    (0..count)
+        .map(|nonce| ethereum_address_to_subaccount_id(&ethereum_address, nonce))
+        .collect()
+}
+
 pub fn get_default_subaccount_id_for_checked_address(addr: &Addr) -> SubaccountId {
     checked_address_to_subaccount_id(addr, 0)
 }
 
 pub fn checked_address_to_subaccount_id(addr: &Addr, nonce: u32) -> SubaccountId {
-    let address_str = bech32_to_hex(addr);
+    try_address_to_subaccount_id(addr, nonce).expect("failed to create subaccount_id")
+}
+
+pub fn try_address_to_subaccount_id(addr: &Addr, nonce: u32) -> StdResult<SubaccountId> {
+    ethereum_address_to_subaccount_id(&try_bech32_to_hex(addr)?, nonce)
+}
+
+pub fn ethereum_address_to_subaccount_id(ethereum_address: &str, nonce: u32) -> StdResult<SubaccountId> {
+    let address = parse_ethereum_address(ethereum_address)?;
     let hex_nonce = format!("{nonce:08x}");
-    let nonce_str = left_pad_with_zeroes(hex_nonce, 24);
+    let nonce_str = left_pad_with_zeroes(hex_nonce, NONCE_LENGTH);
 
-    SubaccountId::new(format!("{address_str}{nonce_str}")).expect("failed to create subaccount_id")
+    SubaccountId::new(format!("{address:?}{nonce_str}"))
 }
 
 pub fn is_default_subaccount(subaccount_id: &SubaccountId) -> bool {
@@ -34,15 +83,36 @@ fn left_pad_with_zeroes(mut input: String, length: usize) -> String {
 }
 
 pub fn bech32_to_hex(addr: &Addr) -> String {
-    let decoded_bytes = bech32::decode(addr.as_str()).unwrap().1;
-    let decoded_h160 = H160::from_slice(&decoded_bytes);
-    let decoded_string = format!("{decoded_h160:?}");
-    decoded_string
+    try_bech32_to_hex(addr).expect("invalid bech32 address")
+}
+
+/// Decodes a bech32 address with any prefix into its lowercase, 0x prefixed Ethereum hex form
+pub fn try_bech32_to_hex(addr: &Addr) -> StdResult<String> {
+    let (_, decoded_bytes) =
+        bech32::decode(addr.as_str()).map_err(|err| StdError::generic_err(format!("Invalid bech32 address {}: {err}", addr.as_str())))?;
+
+    Ok(format!("{:?}", h160_from_slice(&decoded_bytes)?))
 }
 
 pub fn addr_to_bech32(addr: String) -> String {
-    let encoded_bytes = H160::from_str(&addr[2..addr.len()]).unwrap();
-    bech32::encode("inj", encoded_bytes)
+    try_addr_to_bech32(&addr, INJ_PREFIX).expect("invalid ethereum address")
+}
+
+/// Encodes an Ethereum hex address, with or without 0x prefix, as a bech32 address with the given prefix
+pub fn try_addr_to_bech32(ethereum_address: &str, prefix: &str) -> StdResult<String> {
+    if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+        return Err(StdError::generic_err(format!("Invalid bech32 prefix {prefix}")));
+    }
+
+    Ok(bech32::encode(prefix, parse_ethereum_address(ethereum_address)?))
+}
+
+pub fn ethereum_address_to_canonical(ethereum_address: &str) -> StdResult<CanonicalAddr> {
+    Ok(CanonicalAddr::from(parse_ethereum_address(ethereum_address)?.as_bytes()))
+}
+
+pub fn canonical_to_ethereum_address(addr: &CanonicalAddr) -> StdResult<String> {
+    Ok(format!("{:?}", h160_from_slice(addr.as_slice())?))
 }
 
 pub fn subaccount_id_to_ethereum_address(subaccount_id: &SubaccountId) -> String {
@@ -50,6 +120,21 @@ pub fn subaccount_id_to_ethereum_address(subaccount_id: &SubaccountId) -> String
     subaccount_id_str[0..subaccount_id_str.len() - 24].to_string()
 }
 
+pub fn try_subaccount_id_to_ethereum_address(subaccount_id: &SubaccountId) -> StdResult<String> {
+    let subaccount_id_str = subaccount_id.as_str();
+    let ethereum_address = subaccount_id_str
+        .get(..subaccount_id_str.len().saturating_sub(NONCE_LENGTH))
+        .ok_or_else(|| StdError::generic_err(format!("Invalid subaccount id {subaccount_id_str}")))?;
+
+    Ok(format!("{:?}", parse_ethereum_address(ethereum_address)?))
+}
+
+/// Owner of the subaccount as a bech32 address with the given prefix, the address is not validated against the chain's api
+pub fn try_subaccount_id_to_address(subaccount_id: &SubaccountId, prefix: &str) -> StdResult<Addr> {
+    let ethereum_address = try_subaccount_id_to_ethereum_address(subaccount_id)?;
+    Ok(Addr::unchecked(try_addr_to_bech32(&ethereum_address, prefix)?))
+}
+
 pub fn subaccount_id_to_injective_address(subaccount_id: &SubaccountId, deps: &Deps<InjectiveQueryWrapper>) -> StdResult<Addr> {
     let ethereum_address = subaccount_id_to_ethereum_address(subaccount_id);
 
@@ -61,11 +146,33 @@ pub fn subaccount_id_to_unchecked_injective_address(subaccount_id: &SubaccountId
     addr_to_bech32(ethereum_address)
 }
 
+fn parse_ethereum_address(ethereum_address: &str) -> StdResult<H160> {
+    let hex_address = ethereum_address.strip_prefix("0x").unwrap_or(ethereum_address);
+    if hex_address.len() != 40 {
+        return Err(StdError::generic_err(format!("Invalid ethereum address {ethereum_address}")));
+    }
+
+    H160::from_str(hex_address).map_err(|_| StdError::generic_err(format!("Invalid ethereum address {ethereum_address}")))
+}
+
+fn h160_from_slice(bytes: &[u8]) -> StdResult<H160> {
+    if bytes.len() != H160::len_bytes() {
+        return Err(StdError::generic_err(format!(
+            "Invalid address length {}, expected 20 bytes",
+            bytes.len()
+        )));
+    }
+
+    Ok(H160::from_slice(bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         exchange::subaccount::{
-            bech32_to_hex, checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, subaccount_id_to_injective_address,
+            bech32_to_hex, canonical_to_ethereum_address, checked_address_to_subaccount_id, ethereum_address_to_canonical,
+            ethereum_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, subaccount_id_to_injective_address,
+            subaccount_ids_for_address, try_addr_to_bech32, try_address_to_subaccount_id, try_bech32_to_hex, try_subaccount_id_to_address,
             SubaccountId,
         },
         mock_dependencies,
@@ -103,4 +210,61 @@ mod tests {
 
         assert_eq!(address.to_string(), "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv".to_string());
     }
+
+    #[test]
+    fn subaccount_id_nonce_and_owner_test() {
+        let owner = Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv");
+        let subaccount_id = SubaccountId::for_address(&owner, 69).unwrap();
+
+        assert_eq!(subaccount_id.nonce().unwrap(), 69);
+        assert_eq!(subaccount_id.owner_address("inj").unwrap(), owner);
+        assert!(subaccount_id.is_owned_by(&owner));
+        let cosmos_owner = subaccount_id.owner_address("cosmos").unwrap();
+        assert!(cosmos_owner.as_str().starts_with("cosmos1"));
+        assert!(subaccount_id.is_owned_by(&cosmos_owner));
+        let other = crate::exchange::subaccount::addr_to_bech32("0x0000000000000000000000000000000000000001".to_string());
+        assert!(!subaccount_id.is_owned_by(&Addr::unchecked(other)));
+
+        let nonces: Vec<u32> = subaccount_ids_for_address(&owner, 3)
+            .unwrap()
+            .iter()
+            .map(|id| id.nonce().unwrap())
+            .collect();
+        assert_eq!(nonces, vec![0, 1, 2]);
+        assert!(SubaccountId::for_address(&Addr::unchecked("not an address"), 0).is_err());
+        assert!(subaccount_ids_for_address(&Addr::unchecked("not an address"), 3).is_err());
+
+        let too_large = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000100000000");
+        assert!(too_large.nonce().is_err());
+        assert!(SubaccountId::unchecked("0xzz").owner_address("inj").is_err());
+        assert!(!SubaccountId::unchecked("0xzz").is_owned_by(&owner));
+    }
+
+    #[test]
+    fn fallible_address_conversions_test() {
+        let inj_address = Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv");
+        let ethereum_address = "0xb5e09b93aceb70c1711af078922fa256011d7e56";
+
+        assert_eq!(try_bech32_to_hex(&inj_address).unwrap(), ethereum_address);
+        assert!(try_bech32_to_hex(&Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nx")).is_err());
+        assert!(try_bech32_to_hex(&Addr::unchecked("not-an-address")).is_err());
+
+        // same account under another chain prefix
+        let cosmos_address = try_addr_to_bech32(ethereum_address, "cosmos").unwrap();
+        assert!(cosmos_address.starts_with("cosmos1"));
+        assert_eq!(try_bech32_to_hex(&Addr::unchecked(&cosmos_address)).unwrap(), ethereum_address);
+        assert_eq!(try_addr_to_bech32(&ethereum_address[2..], "inj").unwrap(), inj_address.as_str());
+        assert!(try_addr_to_bech32("0x1234", "inj").is_err());
+        assert!(try_addr_to_bech32(ethereum_address, "").is_err());
+
+        let canonical = ethereum_address_to_canonical(ethereum_address).unwrap();
+        assert_eq!(canonical.len(), 20);
+        assert_eq!(canonical_to_ethereum_address(&canonical).unwrap(), ethereum_address);
+        assert!(canonical_to_ethereum_address(&cosmwasm_std::CanonicalAddr::from(vec![1u8; 32])).is_err());
+
+        let subaccount_id = ethereum_address_to_subaccount_id(ethereum_address, 1).unwrap();
+        assert_eq!(subaccount_id, try_address_to_subaccount_id(&inj_address, 1).unwrap());
+        assert_eq!(try_subaccount_id_to_address(&subaccount_id, "inj").unwrap(), inj_address);
+        assert!(try_subaccount_id_to_address(&SubaccountId::unchecked("0x01"), "inj").is_err());
+    }
 }
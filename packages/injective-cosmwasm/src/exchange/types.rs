@@ -46,6 +46,41 @@ pub struct Deposit {
     pub total_balance: FPDecimal,
 }
 
+/// Discount rates of a fee discount tier and the staked amount and volume required to reach it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeDiscountTierInfo {
+    pub maker_discount_rate: FPDecimal,
+    pub taker_discount_rate: FPDecimal,
+    pub staked_amount: FPDecimal,
+    pub volume: FPDecimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeDiscountSchedule {
+    pub bucket_count: u64,
+    pub bucket_duration: i64,
+    /// Quote denoms whose trading fees count towards the fee paid and which are eligible for discounts
+    #[serde(default)]
+    pub quote_denoms: Vec<String>,
+    #[serde(default)]
+    pub tier_infos: Vec<FeeDiscountTierInfo>,
+    #[serde(default, alias = "disqualified_marketIDs")]
+    pub disqualified_market_ids: Vec<MarketId>,
+}
+
+impl FeeDiscountSchedule {
+    /// Discounts only apply to markets quoted in one of the schedule's quote denoms that are not disqualified
+    pub fn is_eligible(&self, quote_denom: &str, market_id: &MarketId) -> bool {
+        self.quote_denoms.iter().any(|denom| denom == quote_denom) && !self.disqualified_market_ids.contains(market_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeDiscountTierTTL {
+    pub tier: u64,
+    pub ttl_timestamp: i64,
+}
+
 /// Response to query for aggregate volume for a given market
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct DenomDecimals {
@@ -83,6 +118,7 @@ pub struct MarketVolume {
 pub enum MarketType {
     Spot,
     Derivative,
+    BinaryOptions,
 }
 
 #[derive(Serialize_repr, Deserialize_repr, Default, Clone, Debug, PartialEq, Eq, JsonSchema, Copy)]
@@ -130,6 +166,10 @@ impl MarketId {
                 let _derivative_market = querier.query_derivative_market(&self)?;
                 Empty {}
             }
+            MarketType::BinaryOptions => {
+                let _binary_options_market = querier.query_binary_options_market(&self)?;
+                Empty {}
+            }
         };
 
         Ok(self)
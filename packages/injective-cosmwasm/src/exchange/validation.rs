@@ -0,0 +1,515 @@
+use cosmwasm_std::StdError;
+use injective_math::{round_to_min_tick, round_to_nearest_tick, round_up_to_min_tick, FPDecimal};
+use thiserror::Error;
+
+use crate::exchange::{
+    derivative::DerivativeOrder,
+    market::GenericMarket,
+    order::{OrderInfo, OrderType},
+    spot::SpotOrder,
+    types::MarketId,
+};
+
+/// Whether the order is dispatched as a limit order or as a market order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderExecution {
+    Limit,
+    Market,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Orders off the tick grid are rejected
+    #[default]
+    Strict,
+    /// Quantities are rounded down and prices are rounded away from the book (buys down, sells up) before validation
+    AutoRound,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("Order is for market {} but was validated against market {}", actual.as_str(), expected.as_str())]
+    MarketMismatch { expected: MarketId, actual: MarketId },
+
+    #[error("Order price must be positive")]
+    ZeroPrice,
+
+    #[error("Order quantity must be positive")]
+    ZeroQuantity,
+
+    #[error("Price {price} is not a multiple of min price tick size {min_price_tick_size}")]
+    InvalidPriceTick { price: FPDecimal, min_price_tick_size: FPDecimal },
+
+    #[error("Trigger price {trigger_price} is not a multiple of min price tick size {min_price_tick_size}")]
+    InvalidTriggerPriceTick {
+        trigger_price: FPDecimal,
+        min_price_tick_size: FPDecimal,
+    },
+
+    #[error("Quantity {quantity} is not a multiple of min quantity tick size {min_quantity_tick_size}")]
+    InvalidQuantityTick {
+        quantity: FPDecimal,
+        min_quantity_tick_size: FPDecimal,
+    },
+
+    #[error("Order notional {notional} is below min notional {min_notional}")]
+    BelowMinNotional { notional: FPDecimal, min_notional: FPDecimal },
+
+    #[error("Reduce-only order must not have margin, got {margin}")]
+    ReduceOnlyWithMargin { margin: FPDecimal },
+
+    #[error("Order which is not reduce-only must have margin")]
+    MissingMargin,
+
+    #[error("Margin {margin} is below the initial margin requirement of {required}")]
+    InsufficientMargin { margin: FPDecimal, required: FPDecimal },
+
+    #[error("Post-only order type {0:?} cannot be sent as a market order")]
+    PostOnlyMarketOrder(OrderType),
+
+    #[error("Atomic order type {0:?} cannot be sent as a limit order")]
+    AtomicLimitOrder(OrderType),
+
+    #[error("Order type {0:?} is not a conditional order type")]
+    NotConditional(OrderType),
+
+    #[error("Conditional order must have a trigger price")]
+    MissingTriggerPrice,
+
+    #[error("Trigger price {trigger_price} of {order_type:?} order would trigger immediately at mark price {mark_price}")]
+    TriggerPriceCrossesMarkPrice {
+        order_type: OrderType,
+        trigger_price: FPDecimal,
+        mark_price: FPDecimal,
+    },
+}
+
+impl From<OrderValidationError> for StdError {
+    fn from(err: OrderValidationError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+pub fn validate_spot_order<M: GenericMarket>(market: &M, order: &SpotOrder, execution: OrderExecution) -> Result<(), OrderValidationError> {
+    validate_order(
+        market,
+        &order.market_id,
+        &order.order_type,
+        &order.order_info,
+        order.trigger_price,
+        execution,
+    )
+}
+
+pub fn validate_derivative_order<M: GenericMarket>(
+    market: &M,
+    order: &DerivativeOrder,
+    execution: OrderExecution,
+    is_reduce_only: bool,
+) -> Result<(), OrderValidationError> {
+    validate_order(
+        market,
+        &order.market_id,
+        &order.order_type,
+        &order.order_info,
+        order.trigger_price,
+        execution,
+    )?;
+
+    match (is_reduce_only, order.margin.is_zero()) {
+        (true, false) => Err(OrderValidationError::ReduceOnlyWithMargin { margin: order.margin }),
+        (false, true) => Err(OrderValidationError::MissingMargin),
+        _ => Ok(()),
+    }
+}
+
+/// Validates a stop or take order, which additionally requires its trigger price to lie beyond the current mark price
+/// in the direction it triggers: above for stop buys and take sells, below for stop sells and take buys
+pub fn validate_conditional_derivative_order<M: GenericMarket>(
+    market: &M,
+    order: &DerivativeOrder,
+    execution: OrderExecution,
+    is_reduce_only: bool,
+    mark_price: FPDecimal,
+) -> Result<(), OrderValidationError> {
+    if !order.order_type.is_conditional() {
+        return Err(OrderValidationError::NotConditional(order.order_type.to_owned()));
+    }
+
+    let trigger_price = order.trigger_price.ok_or(OrderValidationError::MissingTriggerPrice)?;
+    validate_derivative_order(market, order, execution, is_reduce_only)?;
+
+    let is_valid = if order.order_type.is_trigger_price_higher() {
+        trigger_price > mark_price
+    } else {
+        trigger_price < mark_price
+    };
+
+    if !is_valid {
+        return Err(OrderValidationError::TriggerPriceCrossesMarkPrice {
+            order_type: order.order_type.to_owned(),
+            trigger_price,
+            mark_price,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates a spot order, rounding it onto the market's tick grid first in `RoundingMode::AutoRound`
+pub fn prepare_spot_order<M: GenericMarket>(
+    market: &M,
+    mut order: SpotOrder,
+    execution: OrderExecution,
+    rounding_mode: RoundingMode,
+) -> Result<SpotOrder, OrderValidationError> {
+    if rounding_mode == RoundingMode::AutoRound {
+        round_order_info(market, &mut order.order_info, order.order_type.is_buy());
+        order.trigger_price = order
+            .trigger_price
+            .map(|price| round_to_nearest_tick(price, market.get_min_price_tick_size()));
+    }
+
+    validate_spot_order(market, &order, execution)?;
+    Ok(order)
+}
+
+/// Validates a derivative order, rounding it onto the market's tick grid first in `RoundingMode::AutoRound`
+pub fn prepare_derivative_order<M: GenericMarket>(
+    market: &M,
+    mut order: DerivativeOrder,
+    execution: OrderExecution,
+    is_reduce_only: bool,
+    rounding_mode: RoundingMode,
+) -> Result<DerivativeOrder, OrderValidationError> {
+    if rounding_mode == RoundingMode::AutoRound {
+        round_order_info(market, &mut order.order_info, order.order_type.is_buy());
+        order.trigger_price = order
+            .trigger_price
+            .map(|price| round_to_nearest_tick(price, market.get_min_price_tick_size()));
+    }
+
+    validate_derivative_order(market, &order, execution, is_reduce_only)?;
+    Ok(order)
+}
+
+/// Rounds quantity down and price away from the opposite side of the book, so the rounded order is never more aggressive
+pub fn round_order_info<M: GenericMarket>(market: &M, order_info: &mut OrderInfo, is_buy: bool) {
+    order_info.quantity = round_to_min_tick(order_info.quantity, market.min_quantity_tick_size());
+    order_info.price = if is_buy {
+        round_to_min_tick(order_info.price, market.get_min_price_tick_size())
+    } else {
+        round_up_to_min_tick(order_info.price, market.get_min_price_tick_size())
+    };
+}
+
+fn validate_order<M: GenericMarket>(
+    market: &M,
+    market_id: &MarketId,
+    order_type: &OrderType,
+    order_info: &OrderInfo,
+    trigger_price: Option<FPDecimal>,
+    execution: OrderExecution,
+) -> Result<(), OrderValidationError> {
+    if market.get_market_id() != market_id {
+        return Err(OrderValidationError::MarketMismatch {
+            expected: market.get_market_id().to_owned(),
+            actual: market_id.to_owned(),
+        });
+    }
+
+    match execution {
+        OrderExecution::Market if order_type.is_post_only() => return Err(OrderValidationError::PostOnlyMarketOrder(order_type.to_owned())),
+        OrderExecution::Limit if order_type.is_atomic() => return Err(OrderValidationError::AtomicLimitOrder(order_type.to_owned())),
+        _ => {}
+    }
+
+    let OrderInfo { price, quantity, .. } = *order_info;
+    let min_price_tick_size = market.get_min_price_tick_size();
+    let min_quantity_tick_size = market.min_quantity_tick_size();
+
+    if price.is_negative() || price.is_zero() {
+        return Err(OrderValidationError::ZeroPrice);
+    }
+
+    if quantity.is_negative() || quantity.is_zero() {
+        return Err(OrderValidationError::ZeroQuantity);
+    }
+
+    if !is_on_tick(price, min_price_tick_size) {
+        return Err(OrderValidationError::InvalidPriceTick { price, min_price_tick_size });
+    }
+
+    if let Some(trigger_price) = trigger_price {
+        if !is_on_tick(trigger_price, min_price_tick_size) {
+            return Err(OrderValidationError::InvalidTriggerPriceTick {
+                trigger_price,
+                min_price_tick_size,
+            });
+        }
+    }
+
+    if !is_on_tick(quantity, min_quantity_tick_size) {
+        return Err(OrderValidationError::InvalidQuantityTick {
+            quantity,
+            min_quantity_tick_size,
+        });
+    }
+
+    let notional = price * quantity;
+    let min_notional = market.get_min_notional();
+    if notional < min_notional {
+        return Err(OrderValidationError::BelowMinNotional { notional, min_notional });
+    }
+
+    Ok(())
+}
+
+fn is_on_tick(value: FPDecimal, min_tick: FPDecimal) -> bool {
+    min_tick.is_zero() || round_to_min_tick(value, min_tick) == value
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use super::*;
+    use crate::exchange::types::SubaccountId;
+    use crate::test_helpers::testing_helpers::{create_mock_spot_market, test_market_ids};
+    use crate::SpotMarket;
+
+    const SUBACCOUNT_ID: &str = "0xb5c4ad1b3e3c8c0e3e8e1a0f8e8e0f8e1a0f8e8e000000000000000000000001";
+
+    fn market() -> SpotMarket {
+        // price tick 0.01, quantity tick 0.01
+        let mut market = create_mock_spot_market("inj", 0);
+        market.min_notional = FPDecimal::ONE;
+        market
+    }
+
+    fn spot_order(price: &str, quantity: &str, order_type: OrderType) -> SpotOrder {
+        SpotOrder::new(
+            FPDecimal::must_from_str(price),
+            FPDecimal::must_from_str(quantity),
+            order_type,
+            &test_market_ids()[0],
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        )
+    }
+
+    fn derivative_order(margin: &str) -> DerivativeOrder {
+        DerivativeOrder::new(
+            FPDecimal::must_from_str("10"),
+            FPDecimal::must_from_str("1"),
+            FPDecimal::must_from_str(margin),
+            OrderType::Buy,
+            test_market_ids()[0].to_owned(),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn accepts_valid_orders() {
+        assert_eq!(
+            validate_spot_order(&market(), &spot_order("10.5", "2", OrderType::Buy), OrderExecution::Limit),
+            Ok(())
+        );
+        assert_eq!(
+            validate_spot_order(&market(), &spot_order("10.5", "2", OrderType::SellAtomic), OrderExecution::Market),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_off_tick_and_small_orders() {
+        let market = market();
+
+        assert_eq!(
+            validate_spot_order(&market, &spot_order("10.005", "2", OrderType::Buy), OrderExecution::Limit),
+            Err(OrderValidationError::InvalidPriceTick {
+                price: FPDecimal::must_from_str("10.005"),
+                min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            })
+        );
+        assert_eq!(
+            validate_spot_order(&market, &spot_order("10", "2.001", OrderType::Buy), OrderExecution::Limit),
+            Err(OrderValidationError::InvalidQuantityTick {
+                quantity: FPDecimal::must_from_str("2.001"),
+                min_quantity_tick_size: FPDecimal::must_from_str("0.01"),
+            })
+        );
+        assert_eq!(
+            validate_spot_order(&market, &spot_order("0.5", "1", OrderType::Buy), OrderExecution::Limit),
+            Err(OrderValidationError::BelowMinNotional {
+                notional: FPDecimal::must_from_str("0.5"),
+                min_notional: FPDecimal::ONE,
+            })
+        );
+        assert_eq!(
+            validate_spot_order(&market, &spot_order("10", "0", OrderType::Buy), OrderExecution::Limit),
+            Err(OrderValidationError::ZeroQuantity)
+        );
+
+        let mut conditional = spot_order("10", "1", OrderType::StopBuy);
+        conditional.trigger_price = Some(FPDecimal::must_from_str("9.999"));
+        assert!(matches!(
+            validate_spot_order(&market, &conditional, OrderExecution::Limit),
+            Err(OrderValidationError::InvalidTriggerPriceTick { .. })
+        ));
+
+        let mut other_market = market.clone();
+        other_market.market_id = test_market_ids()[1].to_owned();
+        assert!(matches!(
+            validate_spot_order(&other_market, &spot_order("10", "1", OrderType::Buy), OrderExecution::Limit),
+            Err(OrderValidationError::MarketMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_order_type_conflicts() {
+        assert_eq!(
+            validate_spot_order(&market(), &spot_order("10", "1", OrderType::BuyPo), OrderExecution::Market),
+            Err(OrderValidationError::PostOnlyMarketOrder(OrderType::BuyPo))
+        );
+        assert_eq!(
+            validate_spot_order(&market(), &spot_order("10", "1", OrderType::SellAtomic), OrderExecution::Limit),
+            Err(OrderValidationError::AtomicLimitOrder(OrderType::SellAtomic))
+        );
+    }
+
+    #[test]
+    fn checks_derivative_margin_against_reduce_only() {
+        let mut market = crate::exchange::derivative_market::DerivativeMarket {
+            ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "inj".to_string(),
+            oracle_quote: "usdt".to_string(),
+            oracle_type: crate::OracleType::Band,
+            oracle_scale_factor: 0,
+            quote_denom: "usdt".to_string(),
+            market_id: test_market_ids()[0].to_owned(),
+            initial_margin_ratio: FPDecimal::must_from_str("0.1"),
+            maintenance_margin_ratio: FPDecimal::must_from_str("0.05"),
+            maker_fee_rate: FPDecimal::ZERO,
+            taker_fee_rate: FPDecimal::ZERO,
+            isPerpetual: true,
+            status: crate::MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.01"),
+            min_notional: FPDecimal::ZERO,
+            relayer_fee_share_rate: None,
+        };
+
+        assert_eq!(
+            validate_derivative_order(&market, &derivative_order("5"), OrderExecution::Limit, false),
+            Ok(())
+        );
+        assert_eq!(
+            validate_derivative_order(&market, &derivative_order("0"), OrderExecution::Limit, true),
+            Ok(())
+        );
+        assert_eq!(
+            validate_derivative_order(&market, &derivative_order("5"), OrderExecution::Limit, true),
+            Err(OrderValidationError::ReduceOnlyWithMargin { margin: FPDecimal::FIVE })
+        );
+        assert_eq!(
+            validate_derivative_order(&market, &derivative_order("0"), OrderExecution::Limit, false),
+            Err(OrderValidationError::MissingMargin)
+        );
+
+        market.min_notional = FPDecimal::from(100u128);
+        assert!(matches!(
+            validate_derivative_order(&market, &derivative_order("5"), OrderExecution::Limit, false),
+            Err(OrderValidationError::BelowMinNotional { .. })
+        ));
+    }
+
+    #[test]
+    fn auto_round_moves_orders_onto_the_tick_grid() {
+        let market = market();
+
+        let buy = prepare_spot_order(
+            &market,
+            spot_order("10.009", "2.019", OrderType::Buy),
+            OrderExecution::Limit,
+            RoundingMode::AutoRound,
+        )
+        .unwrap();
+        assert_eq!(buy.order_info.price, FPDecimal::must_from_str("10"));
+        assert_eq!(buy.order_info.quantity, FPDecimal::must_from_str("2.01"));
+
+        let sell = prepare_spot_order(
+            &market,
+            spot_order("10.001", "2.019", OrderType::Sell),
+            OrderExecution::Limit,
+            RoundingMode::AutoRound,
+        )
+        .unwrap();
+        assert_eq!(sell.order_info.price, FPDecimal::must_from_str("10.01"));
+
+        assert!(prepare_spot_order(
+            &market,
+            spot_order("10.001", "2", OrderType::Sell),
+            OrderExecution::Limit,
+            RoundingMode::Strict
+        )
+        .is_err());
+
+        // a quantity below one tick rounds down to zero
+        assert_eq!(
+            prepare_spot_order(
+                &market,
+                spot_order("10", "0.009", OrderType::Buy),
+                OrderExecution::Limit,
+                RoundingMode::AutoRound
+            ),
+            Err(OrderValidationError::ZeroQuantity)
+        );
+    }
+
+    #[test]
+    fn checks_conditional_trigger_price_against_mark_price() {
+        let market = market();
+        let conditional_order = |order_type: OrderType, trigger_price: &str| {
+            DerivativeOrder::new_conditional(
+                FPDecimal::must_from_str("10"),
+                FPDecimal::ONE,
+                FPDecimal::FIVE,
+                FPDecimal::must_from_str(trigger_price),
+                order_type,
+                test_market_ids()[0].to_owned(),
+                SubaccountId::unchecked(SUBACCOUNT_ID),
+                None,
+                None,
+            )
+        };
+        let validate = |order: &DerivativeOrder| {
+            validate_conditional_derivative_order(&market, order, OrderExecution::Limit, false, FPDecimal::must_from_str("10"))
+        };
+
+        assert_eq!(validate(&conditional_order(OrderType::StopBuy, "11")), Ok(()));
+        assert_eq!(validate(&conditional_order(OrderType::TakeSell, "11")), Ok(()));
+        assert_eq!(validate(&conditional_order(OrderType::StopSell, "9")), Ok(()));
+        assert_eq!(validate(&conditional_order(OrderType::TakeBuy, "9")), Ok(()));
+        assert_eq!(
+            validate(&conditional_order(OrderType::StopBuy, "9")),
+            Err(OrderValidationError::TriggerPriceCrossesMarkPrice {
+                order_type: OrderType::StopBuy,
+                trigger_price: FPDecimal::must_from_str("9"),
+                mark_price: FPDecimal::must_from_str("10"),
+            })
+        );
+        assert!(validate(&conditional_order(OrderType::TakeBuy, "10")).is_err());
+        assert_eq!(
+            validate(&derivative_order("5")),
+            Err(OrderValidationError::NotConditional(OrderType::Buy))
+        );
+
+        let mut missing_trigger = conditional_order(OrderType::StopSell, "9");
+        missing_trigger.trigger_price = None;
+        assert_eq!(validate(&missing_trigger), Err(OrderValidationError::MissingTriggerPrice));
+    }
+}
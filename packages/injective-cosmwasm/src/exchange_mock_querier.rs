@@ -9,12 +9,18 @@ use cosmwasm_std::{
 
 use injective_math::FPDecimal;
 
+use crate::authz::{
+    response::{GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse},
+    types::PageRequest,
+};
 use crate::exchange::{
+    binary_options_market::BinaryOptionsMarket,
     derivative_market::DerivativeMarket,
     response::QueryOrderbookResponse,
     types::{AtomicMarketOrderAccessLevel, MarketVolume, Params, PriceLevel, VolumeByType},
 };
 use crate::oracle::{
+    response::{HistoricalPriceRecordsResponse, ProviderPriceStateResponse, StorkPriceStatesResponse},
     types::{OracleHistoryOptions, OracleType, PriceState, PythPriceState},
     volatility::TradeHistoryOptions,
 };
@@ -22,11 +28,14 @@ use crate::oracle::{
 use crate::tokenfactory::response::{TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse};
 use crate::wasmx::response::QueryContractRegistrationInfoResponse;
 use crate::{
-    CancellationStrategy, Deposit, DerivativeMarketResponse, ExchangeParamsResponse, FullDerivativeMarket, InjectiveQuery, InjectiveQueryWrapper,
-    MarketMidPriceAndTOBResponse, MarketStatus, MarketVolatilityResponse, OracleInfo, OracleVolatilityResponse, OrderSide,
-    PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PythPriceResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
-    QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket, SpotMarketResponse, SubaccountDepositResponse,
-    SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
+    BinaryOptionsMarketResponse, BinaryOptionsMarketsResponse, CancellationStrategy, Deposit, DerivativeMarketResponse, DerivativeMarketsResponse,
+    DerivativeOrdersByHashesResponse, ExchangeParamsResponse, FeeDiscountAccountInfoResponse, FeeDiscountScheduleResponse, FullDerivativeMarket,
+    InjectiveQuery, InjectiveQueryWrapper, IsOptedOutOfRewardsResponse, MarketMidPriceAndTOBResponse, MarketStatus, MarketVolatilityResponse,
+    OracleInfo, OracleVolatilityResponse, OrderSide, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PythPriceResponse,
+    QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket,
+    SpotMarketResponse, SpotMarketsResponse, SpotOrdersByHashesResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse,
+    SubaccountPositionInMarketResponse, SubaccountPositionsResponse, TradeRewardCampaignResponse, TradeRewardPointsResponse,
+    TraderDerivativeConditionalOrdersResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
 };
 use crate::{MarketId, SubaccountId};
 
@@ -41,6 +50,45 @@ pub fn mock_dependencies() -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier, I
     }
 }
 
+fn default_grants_response_handler() -> QuerierResult {
+    let response = GrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_grantee_grants_response_handler() -> QuerierResult {
+    let response = GranteeGrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_granter_grants_response_handler() -> QuerierResult {
+    let response = GranterGrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_spot_markets_response_handler() -> QuerierResult {
+    let response = SpotMarketsResponse { markets: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_derivative_markets_response_handler() -> QuerierResult {
+    let response = DerivativeMarketsResponse { markets: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_binary_options_markets_response_handler() -> QuerierResult {
+    let response = BinaryOptionsMarketsResponse { markets: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_subaccount_deposit_response_handler() -> QuerierResult {
     let response = SubaccountDepositResponse {
         deposits: Deposit {
@@ -105,6 +153,8 @@ fn default_derivative_market_response_handler(market_id: MarketId) -> QuerierRes
                 status: MarketStatus::Active,
                 min_price_tick_size: FPDecimal::from_str("100000.0").unwrap(),
                 min_quantity_tick_size: FPDecimal::from_str("0.0001").unwrap(),
+                min_notional: FPDecimal::ZERO,
+                relayer_fee_share_rate: Some(FPDecimal::from_str("0.4").unwrap()),
             }),
             info: None,
             mark_price: FPDecimal::ONE,
@@ -113,8 +163,35 @@ fn default_derivative_market_response_handler(market_id: MarketId) -> QuerierRes
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_binary_options_market_response_handler(market_id: MarketId) -> QuerierResult {
+    let response = BinaryOptionsMarketResponse {
+        market: Some(BinaryOptionsMarket {
+            ticker: "ticker".to_string(),
+            oracle_symbol: "oracle_symbol".to_string(),
+            oracle_provider: "oracle_provider".to_string(),
+            oracle_type: OracleType::Provider,
+            oracle_scale_factor: 6,
+            expiration_timestamp: 1_700_000_000,
+            settlement_timestamp: 1_700_003_600,
+            admin: "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv".to_string(),
+            quote_denom: "usdt".to_string(),
+            market_id,
+            maker_fee_rate: FPDecimal::from_str("0.0005").unwrap(),
+            taker_fee_rate: FPDecimal::from_str("0.001").unwrap(),
+            relayer_fee_share_rate: FPDecimal::from_str("0.4").unwrap(),
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::from_str("0.01").unwrap(),
+            min_quantity_tick_size: FPDecimal::from_str("1").unwrap(),
+            settlement_price: None,
+            min_notional: FPDecimal::from_str("0.01").unwrap(),
+        }),
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_subaccount_positions_response_handler() -> QuerierResult {
-    todo!()
+    let response = SubaccountPositionsResponse { state: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
 fn default_subaccount_position_in_market_response_handler() -> QuerierResult {
@@ -132,6 +209,21 @@ fn default_trader_derivative_orders_response_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_spot_orders_by_hashes_response_handler() -> QuerierResult {
+    let response = SpotOrdersByHashesResponse { orders: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_derivative_orders_by_hashes_response_handler() -> QuerierResult {
+    let response = DerivativeOrdersByHashesResponse { orders: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_trader_derivative_conditional_orders_response_handler() -> QuerierResult {
+    let response = TraderDerivativeConditionalOrdersResponse { orders: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_trader_transient_spot_orders_response_handler() -> QuerierResult {
     let response = TraderSpotOrdersResponse { orders: None };
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
@@ -238,6 +330,21 @@ fn default_pyth_price_response_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_stork_price_states_response_handler() -> QuerierResult {
+    let response = StorkPriceStatesResponse { price_states: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_historical_price_records_response_handler() -> QuerierResult {
+    let response = HistoricalPriceRecordsResponse { price_records: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_provider_price_state_response_handler() -> QuerierResult {
+    let response = ProviderPriceStateResponse { price_state: None };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_token_factory_denom_total_supply_handler() -> QuerierResult {
     let response = TokenFactoryDenomSupplyResponse {
         total_supply: Uint128::from(1000u128),
@@ -331,6 +438,14 @@ fn default_derivative_market_orderbook_response_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_binary_options_market_orderbook_response_handler() -> QuerierResult {
+    let response = QueryOrderbookResponse {
+        buys_price_level: vec![PriceLevel::new(FPDecimal::must_from_str("0.4"), 10u128.into())],
+        sells_price_level: vec![PriceLevel::new(FPDecimal::must_from_str("0.6"), 10u128.into())],
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_market_atomic_execution_fee_multiplier_response_handler() -> QuerierResult {
     let response = QueryMarketAtomicExecutionFeeMultiplierResponse {
         multiplier: FPDecimal::from_str("2.0").unwrap(),
@@ -338,6 +453,43 @@ fn default_market_atomic_execution_fee_multiplier_response_handler() -> QuerierR
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_fee_discount_schedule_response_handler() -> QuerierResult {
+    let response = FeeDiscountScheduleResponse { fee_discount_schedule: None };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_is_opted_out_of_rewards_response_handler() -> QuerierResult {
+    let response = IsOptedOutOfRewardsResponse { is_opted_out: false };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_trade_reward_points_response_handler(accounts: Vec<String>) -> QuerierResult {
+    let response = TradeRewardPointsResponse {
+        account_trade_reward_points: vec![FPDecimal::ZERO; accounts.len()],
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_trade_reward_campaign_response_handler() -> QuerierResult {
+    let response = TradeRewardCampaignResponse {
+        trading_reward_campaign_info: None,
+        trading_reward_pool_campaign_schedule: vec![],
+        total_trade_reward_points: FPDecimal::ZERO,
+        pending_trading_reward_pool_campaign_schedule: vec![],
+        pending_total_trade_reward_points: vec![],
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_fee_discount_account_info_response_handler() -> QuerierResult {
+    let response = FeeDiscountAccountInfoResponse {
+        tier_level: 0,
+        account_info: None,
+        account_ttl: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 pub trait HandlesSmartQuery {
     fn handle(&self, contract_addr: &str, msg: &Binary) -> QuerierResult;
 }
@@ -381,6 +533,18 @@ pub trait HandlesTraderDerivativeOrdersToCancelUpToAmountQuery {
     ) -> QuerierResult;
 }
 
+pub trait HandlesGrantsQuery {
+    fn handle(&self, granter: String, grantee: String, msg_type_url: String, pagination: Option<PageRequest>) -> QuerierResult;
+}
+
+pub trait HandlesAddressGrantsQuery {
+    fn handle(&self, address: String, pagination: Option<PageRequest>) -> QuerierResult;
+}
+
+pub trait HandlesMarketsQuery {
+    fn handle(&self, status: Option<MarketStatus>, quote_denom: Option<String>) -> QuerierResult;
+}
+
 pub trait HandlesMarketIdQuery {
     fn handle(&self, market_id: MarketId) -> QuerierResult;
 }
@@ -393,6 +557,10 @@ pub trait HandlesMarketAndSubaccountQuery {
     fn handle(&self, market_id: MarketId, subaccount_id: SubaccountId) -> QuerierResult;
 }
 
+pub trait HandlesOrdersByHashesQuery {
+    fn handle(&self, market_id: MarketId, subaccount_id: SubaccountId, order_hashes: Vec<String>) -> QuerierResult;
+}
+
 pub trait HandlesSubaccountAndDenomQuery {
     fn handle(&self, subaccount_id: SubaccountId, denom: String) -> QuerierResult;
 }
@@ -418,6 +586,18 @@ pub trait HandlesPythPriceQuery {
     fn handle(&self, price_id: String) -> QuerierResult;
 }
 
+pub trait HandlesStorkPriceStatesQuery {
+    fn handle(&self) -> QuerierResult;
+}
+
+pub trait HandlesHistoricalPriceRecordsQuery {
+    fn handle(&self, oracle: OracleType, symbol_id: String) -> QuerierResult;
+}
+
+pub trait HandlesProviderPriceStateQuery {
+    fn handle(&self, provider: String, symbol: String) -> QuerierResult;
+}
+
 pub trait HandlesMarketVolatilityQuery {
     fn handle(&self, market_id: MarketId, trade_history_options: TradeHistoryOptions) -> QuerierResult;
 }
@@ -470,6 +650,18 @@ pub trait HandlesExchangeParamsQuery {
     fn handle(&self) -> QuerierResult;
 }
 
+pub trait HandlesFeeDiscountScheduleQuery {
+    fn handle(&self) -> QuerierResult;
+}
+
+pub trait HandlesTradeRewardPointsQuery {
+    fn handle(&self, accounts: Vec<String>, pending_pool_timestamp: Option<i64>) -> QuerierResult;
+}
+
+pub trait HandlesTradeRewardCampaignQuery {
+    fn handle(&self) -> QuerierResult;
+}
+
 pub struct WasmMockQuerier {
     pub smart_query_handler: Option<Box<dyn HandlesSmartQuery>>,
     pub raw_query_handler: Option<Box<dyn HandlesRawQuery>>,
@@ -486,6 +678,9 @@ pub struct WasmMockQuerier {
     pub subaccount_position_in_market_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub subaccount_effective_position_in_market_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub trader_derivative_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
+    pub trader_derivative_conditional_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
+    pub spot_orders_by_hashes_response_handler: Option<Box<dyn HandlesOrdersByHashesQuery>>,
+    pub derivative_orders_by_hashes_response_handler: Option<Box<dyn HandlesOrdersByHashesQuery>>,
     pub trader_transient_spot_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub trader_transient_derivative_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub perpetual_market_info_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
@@ -501,6 +696,9 @@ pub struct WasmMockQuerier {
     pub oracle_volatility_response_handler: Option<Box<dyn HandlesOracleVolatilityQuery>>,
     pub oracle_price_response_handler: Option<Box<dyn HandlesOraclePriceQuery>>,
     pub pyth_price_response_handler: Option<Box<dyn HandlesPythPriceQuery>>,
+    pub stork_price_states_response_handler: Option<Box<dyn HandlesStorkPriceStatesQuery>>,
+    pub historical_price_records_response_handler: Option<Box<dyn HandlesHistoricalPriceRecordsQuery>>,
+    pub provider_price_state_response_handler: Option<Box<dyn HandlesProviderPriceStateQuery>>,
     pub token_factory_denom_total_supply_handler: Option<Box<dyn HandlesDenomSupplyQuery>>,
     pub token_factory_denom_creation_fee_handler: Option<Box<dyn HandlesFeeQuery>>,
     pub balance_query_handler: Option<Box<dyn HandlesBankBalanceQuery>>,
@@ -510,6 +708,20 @@ pub struct WasmMockQuerier {
     pub spot_market_orderbook_response_handler: Option<Box<dyn HandlesPriceLevelsQuery>>,
     pub derivative_market_orderbook_response_handler: Option<Box<dyn HandlesDerivativePriceLevelsQuery>>,
     pub market_atomic_execution_fee_multiplier_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
+    pub fee_discount_schedule_response_handler: Option<Box<dyn HandlesFeeDiscountScheduleQuery>>,
+    pub fee_discount_account_info_response_handler: Option<Box<dyn HandlesByAddressQuery>>,
+    pub is_opted_out_of_rewards_response_handler: Option<Box<dyn HandlesByAddressQuery>>,
+    pub trade_reward_points_response_handler: Option<Box<dyn HandlesTradeRewardPointsQuery>>,
+    pub pending_trade_reward_points_response_handler: Option<Box<dyn HandlesTradeRewardPointsQuery>>,
+    pub trade_reward_campaign_response_handler: Option<Box<dyn HandlesTradeRewardCampaignQuery>>,
+    pub binary_options_market_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
+    pub binary_options_market_orderbook_response_handler: Option<Box<dyn HandlesDerivativePriceLevelsQuery>>,
+    pub spot_markets_response_handler: Option<Box<dyn HandlesMarketsQuery>>,
+    pub derivative_markets_response_handler: Option<Box<dyn HandlesMarketsQuery>>,
+    pub binary_options_markets_response_handler: Option<Box<dyn HandlesMarketsQuery>>,
+    pub grants_response_handler: Option<Box<dyn HandlesGrantsQuery>>,
+    pub grantee_grants_response_handler: Option<Box<dyn HandlesAddressGrantsQuery>>,
+    pub granter_grants_response_handler: Option<Box<dyn HandlesAddressGrantsQuery>>,
 }
 
 impl Querier for WasmMockQuerier {
@@ -627,6 +839,28 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(market_id, subaccount_id),
                     None => default_trader_derivative_orders_response_handler(),
                 },
+                InjectiveQuery::SpotOrdersByHashes {
+                    market_id,
+                    subaccount_id,
+                    order_hashes,
+                } => match &self.spot_orders_by_hashes_response_handler {
+                    Some(handler) => handler.handle(market_id, subaccount_id, order_hashes),
+                    None => default_spot_orders_by_hashes_response_handler(),
+                },
+                InjectiveQuery::DerivativeOrdersByHashes {
+                    market_id,
+                    subaccount_id,
+                    order_hashes,
+                } => match &self.derivative_orders_by_hashes_response_handler {
+                    Some(handler) => handler.handle(market_id, subaccount_id, order_hashes),
+                    None => default_derivative_orders_by_hashes_response_handler(),
+                },
+                InjectiveQuery::TraderDerivativeConditionalOrders { market_id, subaccount_id } => {
+                    match &self.trader_derivative_conditional_orders_response_handler {
+                        Some(handler) => handler.handle(market_id, subaccount_id),
+                        None => default_trader_derivative_conditional_orders_response_handler(),
+                    }
+                }
                 InjectiveQuery::TraderTransientSpotOrders { market_id, subaccount_id } => match &self.trader_transient_spot_orders_response_handler {
                     Some(handler) => handler.handle(market_id, subaccount_id),
                     None => default_trader_transient_spot_orders_response_handler(),
@@ -693,6 +927,18 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(price_id),
                     None => default_pyth_price_response_handler(),
                 },
+                InjectiveQuery::StorkPriceStates {} => match &self.stork_price_states_response_handler {
+                    Some(handler) => handler.handle(),
+                    None => default_stork_price_states_response_handler(),
+                },
+                InjectiveQuery::HistoricalPriceRecords { oracle, symbol_id } => match &self.historical_price_records_response_handler {
+                    Some(handler) => handler.handle(oracle, symbol_id),
+                    None => default_historical_price_records_response_handler(),
+                },
+                InjectiveQuery::ProviderPriceState { provider, symbol } => match &self.provider_price_state_response_handler {
+                    Some(handler) => handler.handle(provider, symbol),
+                    None => default_provider_price_state_response_handler(),
+                },
                 InjectiveQuery::TokenFactoryDenomTotalSupply { denom } => match &self.token_factory_denom_total_supply_handler {
                     Some(handler) => handler.handle(denom),
                     None => default_token_factory_denom_total_supply_handler(),
@@ -719,6 +965,70 @@ impl WasmMockQuerier {
                         None => default_market_atomic_execution_fee_multiplier_response_handler(),
                     }
                 }
+                InjectiveQuery::FeeDiscountSchedule {} => match &self.fee_discount_schedule_response_handler {
+                    Some(handler) => handler.handle(),
+                    None => default_fee_discount_schedule_response_handler(),
+                },
+                InjectiveQuery::FeeDiscountAccountInfo { account } => match &self.fee_discount_account_info_response_handler {
+                    Some(handler) => handler.handle(account),
+                    None => default_fee_discount_account_info_response_handler(),
+                },
+                InjectiveQuery::IsOptedOutOfRewards { account } => match &self.is_opted_out_of_rewards_response_handler {
+                    Some(handler) => handler.handle(account),
+                    None => default_is_opted_out_of_rewards_response_handler(),
+                },
+                InjectiveQuery::TradeRewardPoints { accounts } => match &self.trade_reward_points_response_handler {
+                    Some(handler) => handler.handle(accounts, None),
+                    None => default_trade_reward_points_response_handler(accounts),
+                },
+                InjectiveQuery::PendingTradeRewardPoints {
+                    accounts,
+                    pending_pool_timestamp,
+                } => match &self.pending_trade_reward_points_response_handler {
+                    Some(handler) => handler.handle(accounts, Some(pending_pool_timestamp)),
+                    None => default_trade_reward_points_response_handler(accounts),
+                },
+                InjectiveQuery::TradeRewardCampaign {} => match &self.trade_reward_campaign_response_handler {
+                    Some(handler) => handler.handle(),
+                    None => default_trade_reward_campaign_response_handler(),
+                },
+                InjectiveQuery::BinaryOptionsMarket { market_id } => match &self.binary_options_market_response_handler {
+                    Some(handler) => handler.handle(market_id),
+                    None => default_binary_options_market_response_handler(market_id),
+                },
+                InjectiveQuery::BinaryOptionsOrderbook { market_id, .. } => match &self.binary_options_market_orderbook_response_handler {
+                    Some(handler) => handler.handle(market_id),
+                    None => default_binary_options_market_orderbook_response_handler(),
+                },
+                InjectiveQuery::SpotMarkets { status, quote_denom } => match &self.spot_markets_response_handler {
+                    Some(handler) => handler.handle(status, quote_denom),
+                    None => default_spot_markets_response_handler(),
+                },
+                InjectiveQuery::DerivativeMarkets { status, quote_denom } => match &self.derivative_markets_response_handler {
+                    Some(handler) => handler.handle(status, quote_denom),
+                    None => default_derivative_markets_response_handler(),
+                },
+                InjectiveQuery::BinaryOptionsMarkets { status, quote_denom } => match &self.binary_options_markets_response_handler {
+                    Some(handler) => handler.handle(status, quote_denom),
+                    None => default_binary_options_markets_response_handler(),
+                },
+                InjectiveQuery::Grants {
+                    granter,
+                    grantee,
+                    msg_type_url,
+                    pagination,
+                } => match &self.grants_response_handler {
+                    Some(handler) => handler.handle(granter, grantee, msg_type_url, pagination),
+                    None => default_grants_response_handler(),
+                },
+                InjectiveQuery::GranteeGrants { grantee, pagination } => match &self.grantee_grants_response_handler {
+                    Some(handler) => handler.handle(grantee, pagination),
+                    None => default_grantee_grants_response_handler(),
+                },
+                InjectiveQuery::GranterGrants { granter, pagination } => match &self.granter_grants_response_handler {
+                    Some(handler) => handler.handle(granter, pagination),
+                    None => default_granter_grants_response_handler(),
+                },
             },
             _ => panic!("Unknown query"),
         }
@@ -749,6 +1059,9 @@ impl WasmMockQuerier {
             subaccount_position_in_market_response_handler: None,
             subaccount_effective_position_in_market_response_handler: None,
             trader_derivative_orders_response_handler: None,
+            trader_derivative_conditional_orders_response_handler: None,
+            spot_orders_by_hashes_response_handler: None,
+            derivative_orders_by_hashes_response_handler: None,
             trader_transient_spot_orders_response_handler: None,
             trader_transient_derivative_orders_response_handler: None,
             perpetual_market_info_response_handler: None,
@@ -763,6 +1076,9 @@ impl WasmMockQuerier {
             oracle_volatility_response_handler: None,
             oracle_price_response_handler: None,
             pyth_price_response_handler: None,
+            stork_price_states_response_handler: None,
+            historical_price_records_response_handler: None,
+            provider_price_state_response_handler: None,
             token_factory_denom_total_supply_handler: None,
             token_factory_denom_creation_fee_handler: None,
             balance_query_handler: None,
@@ -772,7 +1088,21 @@ impl WasmMockQuerier {
             spot_market_orderbook_response_handler: None,
             derivative_market_orderbook_response_handler: None,
             market_atomic_execution_fee_multiplier_response_handler: None,
+            fee_discount_schedule_response_handler: None,
+            fee_discount_account_info_response_handler: None,
+            is_opted_out_of_rewards_response_handler: None,
+            trade_reward_points_response_handler: None,
+            pending_trade_reward_points_response_handler: None,
+            trade_reward_campaign_response_handler: None,
             total_supply_handler: None,
+            binary_options_market_response_handler: None,
+            binary_options_market_orderbook_response_handler: None,
+            spot_markets_response_handler: None,
+            derivative_markets_response_handler: None,
+            binary_options_markets_response_handler: None,
+            grants_response_handler: None,
+            grantee_grants_response_handler: None,
+            granter_grants_response_handler: None,
         }
     }
 }
@@ -808,26 +1138,45 @@ pub mod handlers {
 
     use injective_math::FPDecimal;
 
-    use crate::exchange::response::QueryOrderbookResponse;
-    use crate::exchange_mock_querier::{HandlesByAddressQuery, HandlesDenomSupplyQuery, HandlesFeeQuery};
-    use crate::oracle::{response::OraclePriceResponse, types::PricePairState};
+    use crate::exchange::{binary_options_market::BinaryOptionsMarket, response::QueryOrderbookResponse};
+    use crate::exchange_mock_querier::{
+        HandlesByAddressQuery, HandlesDenomSupplyQuery, HandlesFeeDiscountScheduleQuery, HandlesFeeQuery, HandlesTradeRewardCampaignQuery,
+        HandlesTradeRewardPointsQuery,
+    };
+    use crate::oracle::{
+        response::{HistoricalPriceRecordsResponse, OraclePriceResponse, ProviderPriceStateResponse, PythPriceResponse, StorkPriceStatesResponse},
+        types::{PricePairState, PriceRecords, PriceState, PythPriceState, StorkPriceState},
+    };
     use crate::tokenfactory::response::{TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse};
     use crate::wasmx::{response::QueryContractRegistrationInfoResponse, types::RegisteredContract};
     use crate::{
-        exchange_mock_querier::TestCoin, CancellationStrategy, Deposit, DerivativeMarket, DerivativeMarketResponse, EffectivePosition,
-        FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesOracleVolatilityQuery,
-        HandlesPriceLevelsQuery, HandlesRawQuery, HandlesSmartQuery, HandlesSubaccountAndDenomQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery,
-        MarketId, MetadataStatistics, OracleVolatilityResponse, OrderSide, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse,
-        SpotMarket, SpotMarketResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId,
-        SubaccountPositionInMarketResponse, TradeRecord, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder,
-        TrimmedSpotLimitOrder,
+        exchange_mock_querier::TestCoin, BinaryOptionsMarketResponse, CancellationStrategy, Deposit, DerivativeMarket, DerivativeMarketResponse,
+        DerivativeOrdersByHashesResponse, EffectivePosition, FeeDiscountAccountInfoResponse, FeeDiscountSchedule, FeeDiscountScheduleResponse,
+        FeeDiscountTierInfo, FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery,
+        HandlesOracleVolatilityQuery, HandlesOrdersByHashesQuery, HandlesPriceLevelsQuery, HandlesRawQuery, HandlesSmartQuery,
+        HandlesSubaccountAndDenomQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery, IsOptedOutOfRewardsResponse, MarketId, MetadataStatistics,
+        OracleVolatilityResponse, OrderSide, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket, SpotMarketResponse,
+        SpotOrdersByHashesResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId,
+        SubaccountPositionInMarketResponse, TradeRecord, TradeRewardCampaignResponse, TradeRewardPointsResponse,
+        TraderDerivativeConditionalOrdersResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeConditionalOrder,
+        TrimmedDerivativeLimitOrder, TrimmedSpotLimitOrder,
     };
     use crate::{
         HandlesBankAllBalancesQuery, HandlesBankBalanceQuery, HandlesCodeInfo, HandlesContractInfo,
         HandlesTraderDerivativeOrdersToCancelUpToAmountQuery, MarketMidPriceAndTOBResponse, OracleType,
     };
 
-    use super::{HandlesOraclePriceQuery, TestDeposit};
+    use super::{
+        HandlesAddressGrantsQuery, HandlesGrantsQuery, HandlesHistoricalPriceRecordsQuery, HandlesMarketsQuery, HandlesOraclePriceQuery,
+        HandlesProviderPriceStateQuery, HandlesPythPriceQuery, HandlesStorkPriceStatesQuery, HandlesSubaccountIdQuery, TestDeposit,
+    };
+    use crate::authz::{
+        response::{Grant, GrantAuthorization, GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse, PageResponse},
+        types::PageRequest,
+    };
+    use crate::exchange::market::{GenericMarket, MarketStatus};
+    use crate::exchange::{derivative::DerivativePosition, response::SubaccountPositionsResponse};
+    use crate::{BinaryOptionsMarketsResponse, DerivativeMarketsResponse, SpotMarketsResponse};
 
     pub fn create_subaccount_deposit_handler(coins: Vec<TestCoin>) -> Option<Box<dyn HandlesSubaccountAndDenomQuery>> {
         struct Temp {
@@ -878,6 +1227,32 @@ pub mod handlers {
         Some(Box::new(Temp { deposits }))
     }
 
+    /// Deposits keyed by subaccount and denom, missing entries return an empty deposit
+    pub fn create_subaccount_deposits_by_subaccount_handler(
+        deposits: Vec<(SubaccountId, String, Deposit)>,
+    ) -> Option<Box<dyn HandlesSubaccountAndDenomQuery>> {
+        struct Temp {
+            deposits: Vec<(SubaccountId, String, Deposit)>,
+        }
+        impl HandlesSubaccountAndDenomQuery for Temp {
+            fn handle(&self, subaccount_id: SubaccountId, denom: String) -> QuerierResult {
+                let deposits = self
+                    .deposits
+                    .iter()
+                    .find(|(id, deposit_denom, _)| id == &subaccount_id && deposit_denom == &denom)
+                    .map(|(_, _, deposit)| deposit.to_owned())
+                    .unwrap_or(Deposit {
+                        available_balance: FPDecimal::ZERO,
+                        total_balance: FPDecimal::ZERO,
+                    });
+
+                let response = SubaccountDepositResponse { deposits };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { deposits }))
+    }
+
     pub fn create_subaccount_deposit_err_returning_handler() -> Option<Box<dyn HandlesSubaccountAndDenomQuery>> {
         struct A();
         impl HandlesSubaccountAndDenomQuery for A {
@@ -903,6 +1278,172 @@ pub mod handlers {
         Some(Box::new(Temp { market }))
     }
 
+    /// Pages are addressed by the big-endian index of their first item, which is returned as next_key
+    fn paginate<T: Clone>(items: Vec<T>, pagination: Option<PageRequest>) -> (Vec<T>, Option<PageResponse>) {
+        let pagination = pagination.unwrap_or_default();
+        let start = match pagination.key {
+            Some(key) => u64::from_be_bytes(key.try_into().expect("invalid page key")) as usize,
+            None => pagination.offset.unwrap_or_default() as usize,
+        };
+        let end = match pagination.limit {
+            Some(limit) => (start + limit as usize).min(items.len()),
+            None => items.len(),
+        };
+        let next_key = (end < items.len()).then(|| (end as u64).to_be_bytes().to_vec());
+        let total = pagination.count_total.then_some(items.len() as u64);
+
+        (items[start.min(end)..end].to_vec(), Some(PageResponse { next_key, total }))
+    }
+
+    pub fn create_grants_handler(grants: Vec<GrantAuthorization>) -> Option<Box<dyn HandlesGrantsQuery>> {
+        struct Temp {
+            grants: Vec<GrantAuthorization>,
+        }
+        impl HandlesGrantsQuery for Temp {
+            fn handle(&self, granter: String, grantee: String, msg_type_url: String, pagination: Option<PageRequest>) -> QuerierResult {
+                let matching: Vec<Grant> = self
+                    .grants
+                    .iter()
+                    .filter(|grant| grant.granter == granter && grant.grantee == grantee)
+                    .filter(|grant| {
+                        msg_type_url.is_empty()
+                            || grant
+                                .decode_authorization()
+                                .map(|authorization| authorization.allows_msg(&msg_type_url))
+                                .unwrap_or_default()
+                    })
+                    .map(|grant| Grant {
+                        authorization: grant.authorization.to_owned(),
+                        expiration: grant.expiration,
+                    })
+                    .collect();
+                let (grants, pagination) = paginate(matching, pagination);
+                let response = GrantsResponse {
+                    grants: Some(grants),
+                    pagination,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    pub fn create_grantee_grants_handler(grants: Vec<GrantAuthorization>) -> Option<Box<dyn HandlesAddressGrantsQuery>> {
+        struct Temp {
+            grants: Vec<GrantAuthorization>,
+        }
+        impl HandlesAddressGrantsQuery for Temp {
+            fn handle(&self, grantee: String, pagination: Option<PageRequest>) -> QuerierResult {
+                let matching = self.grants.iter().filter(|grant| grant.grantee == grantee).cloned().collect();
+                let (grants, pagination) = paginate(matching, pagination);
+                let response = GranteeGrantsResponse {
+                    grants: Some(grants),
+                    pagination,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    pub fn create_granter_grants_handler(grants: Vec<GrantAuthorization>) -> Option<Box<dyn HandlesAddressGrantsQuery>> {
+        struct Temp {
+            grants: Vec<GrantAuthorization>,
+        }
+        impl HandlesAddressGrantsQuery for Temp {
+            fn handle(&self, granter: String, pagination: Option<PageRequest>) -> QuerierResult {
+                let matching = self.grants.iter().filter(|grant| grant.granter == granter).cloned().collect();
+                let (grants, pagination) = paginate(matching, pagination);
+                let response = GranterGrantsResponse {
+                    grants: Some(grants),
+                    pagination,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    pub fn create_spot_markets_handler(markets: Vec<SpotMarket>) -> Option<Box<dyn HandlesMarketsQuery>> {
+        struct Temp {
+            markets: Vec<SpotMarket>,
+        }
+        impl HandlesMarketsQuery for Temp {
+            fn handle(&self, status: Option<MarketStatus>, quote_denom: Option<String>) -> QuerierResult {
+                let response = SpotMarketsResponse {
+                    markets: self
+                        .markets
+                        .iter()
+                        .filter(|market| market.matches_filter(status, quote_denom.as_deref()))
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { markets }))
+    }
+
+    pub fn create_derivative_markets_handler(markets: Vec<FullDerivativeMarket>) -> Option<Box<dyn HandlesMarketsQuery>> {
+        struct Temp {
+            markets: Vec<FullDerivativeMarket>,
+        }
+        impl HandlesMarketsQuery for Temp {
+            fn handle(&self, status: Option<MarketStatus>, quote_denom: Option<String>) -> QuerierResult {
+                let response = DerivativeMarketsResponse {
+                    markets: self
+                        .markets
+                        .iter()
+                        .filter(|full_market| {
+                            full_market
+                                .market
+                                .as_ref()
+                                .is_some_and(|market| market.matches_filter(status, quote_denom.as_deref()))
+                        })
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { markets }))
+    }
+
+    pub fn create_binary_options_markets_handler(markets: Vec<BinaryOptionsMarket>) -> Option<Box<dyn HandlesMarketsQuery>> {
+        struct Temp {
+            markets: Vec<BinaryOptionsMarket>,
+        }
+        impl HandlesMarketsQuery for Temp {
+            fn handle(&self, status: Option<MarketStatus>, quote_denom: Option<String>) -> QuerierResult {
+                let response = BinaryOptionsMarketsResponse {
+                    markets: self
+                        .markets
+                        .iter()
+                        .filter(|market| market.matches_filter(status, quote_denom.as_deref()))
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { markets }))
+    }
+
+    pub fn create_binary_options_market_handler(market: Option<BinaryOptionsMarket>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            market: Option<BinaryOptionsMarket>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, _: MarketId) -> QuerierResult {
+                let response = BinaryOptionsMarketResponse {
+                    market: self.market.to_owned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { market }))
+    }
+
     pub fn create_orderbook_response_handler(orderbooks: HashMap<MarketId, Vec<PriceLevel>>) -> Option<Box<dyn HandlesPriceLevelsQuery>> {
         struct Temp {
             orderbooks: HashMap<MarketId, Vec<PriceLevel>>,
@@ -1061,6 +1602,65 @@ pub mod handlers {
         Some(Box::new(Temp { orders }))
     }
 
+    /// Returns the orders whose hash was queried
+    pub fn create_spot_orders_by_hashes_handler(orders: Vec<TrimmedSpotLimitOrder>) -> Option<Box<dyn HandlesOrdersByHashesQuery>> {
+        struct Temp {
+            orders: Vec<TrimmedSpotLimitOrder>,
+        }
+        impl HandlesOrdersByHashesQuery for Temp {
+            fn handle(&self, _: MarketId, _: SubaccountId, order_hashes: Vec<String>) -> QuerierResult {
+                let response = SpotOrdersByHashesResponse {
+                    orders: self
+                        .orders
+                        .iter()
+                        .filter(|order| order_hashes.contains(&order.order_hash))
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { orders }))
+    }
+
+    /// Returns the orders whose hash was queried
+    pub fn create_derivative_orders_by_hashes_handler(orders: Vec<TrimmedDerivativeLimitOrder>) -> Option<Box<dyn HandlesOrdersByHashesQuery>> {
+        struct Temp {
+            orders: Vec<TrimmedDerivativeLimitOrder>,
+        }
+        impl HandlesOrdersByHashesQuery for Temp {
+            fn handle(&self, _: MarketId, _: SubaccountId, order_hashes: Vec<String>) -> QuerierResult {
+                let response = DerivativeOrdersByHashesResponse {
+                    orders: self
+                        .orders
+                        .iter()
+                        .filter(|order| order_hashes.contains(&order.order_hash))
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { orders }))
+    }
+
+    pub fn create_trader_derivative_conditional_orders_handler(
+        orders: Vec<TrimmedDerivativeConditionalOrder>,
+    ) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
+        struct Temp {
+            orders: Vec<TrimmedDerivativeConditionalOrder>,
+        }
+        impl HandlesMarketAndSubaccountQuery for Temp {
+            fn handle(&self, _: MarketId, _: SubaccountId) -> QuerierResult {
+                let response = TraderDerivativeConditionalOrdersResponse {
+                    orders: self.orders.to_owned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { orders }))
+    }
+
     pub fn create_subaccount_effective_position_in_market_handler(
         position: Option<EffectivePosition>,
     ) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
@@ -1080,6 +1680,28 @@ pub mod handlers {
         Some(Box::new(Temp { position }))
     }
 
+    pub fn create_subaccount_positions_handler(positions: Vec<DerivativePosition>) -> Option<Box<dyn HandlesSubaccountIdQuery>> {
+        struct Temp {
+            positions: Vec<DerivativePosition>,
+        }
+
+        impl HandlesSubaccountIdQuery for Temp {
+            fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+                let response = SubaccountPositionsResponse {
+                    state: self
+                        .positions
+                        .iter()
+                        .filter(|position| position.subaccount_id == subaccount_id)
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+
+        Some(Box::new(Temp { positions }))
+    }
+
     pub fn create_subaccount_position_in_market_handler(position: Option<Position>) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
         struct Temp {
             position: Option<Position>,
@@ -1201,6 +1823,83 @@ pub mod handlers {
         }))
     }
 
+    pub fn create_pyth_price_handler(price_state: PythPriceState) -> Option<Box<dyn HandlesPythPriceQuery>> {
+        struct Temp {
+            price_state: PythPriceState,
+        }
+        impl HandlesPythPriceQuery for Temp {
+            fn handle(&self, _: String) -> QuerierResult {
+                let response = PythPriceResponse {
+                    price_state: Some(self.price_state.clone()),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { price_state }))
+    }
+
+    pub fn create_stork_price_states_handler(price_states: Vec<StorkPriceState>) -> Option<Box<dyn HandlesStorkPriceStatesQuery>> {
+        struct Temp {
+            price_states: Vec<StorkPriceState>,
+        }
+        impl HandlesStorkPriceStatesQuery for Temp {
+            fn handle(&self) -> QuerierResult {
+                let response = StorkPriceStatesResponse {
+                    price_states: self.price_states.clone(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { price_states }))
+    }
+
+    /// Returns the records matching the queried oracle type and symbol id
+    pub fn create_historical_price_records_handler(price_records: Vec<PriceRecords>) -> Option<Box<dyn HandlesHistoricalPriceRecordsQuery>> {
+        struct Temp {
+            price_records: Vec<PriceRecords>,
+        }
+        impl HandlesHistoricalPriceRecordsQuery for Temp {
+            fn handle(&self, oracle: OracleType, symbol_id: String) -> QuerierResult {
+                let response = HistoricalPriceRecordsResponse {
+                    price_records: self
+                        .price_records
+                        .iter()
+                        .filter(|records| records.oracle == oracle && records.symbol_id == symbol_id)
+                        .cloned()
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { price_records }))
+    }
+
+    /// Returns `price_state` for the given provider and symbol, no price for anything else
+    pub fn create_provider_price_state_handler(
+        provider: String,
+        symbol: String,
+        price_state: PriceState,
+    ) -> Option<Box<dyn HandlesProviderPriceStateQuery>> {
+        struct Temp {
+            provider: String,
+            symbol: String,
+            price_state: PriceState,
+        }
+        impl HandlesProviderPriceStateQuery for Temp {
+            fn handle(&self, provider: String, symbol: String) -> QuerierResult {
+                let response = ProviderPriceStateResponse {
+                    price_state: (provider == self.provider && symbol == self.symbol).then(|| self.price_state.clone()),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp {
+            provider,
+            symbol,
+            price_state,
+        }))
+    }
+
     pub fn create_bank_supply_handler(supply: Uint128) -> Option<Box<dyn HandlesDenomSupplyQuery>> {
         struct Temp {
             supply: Uint128,
@@ -1299,6 +1998,85 @@ pub mod handlers {
         Some(Box::new(Temp { multiplier }))
     }
 
+    pub fn create_fee_discount_schedule_handler(schedule: FeeDiscountSchedule) -> Option<Box<dyn HandlesFeeDiscountScheduleQuery>> {
+        struct Temp {
+            schedule: FeeDiscountSchedule,
+        }
+        impl HandlesFeeDiscountScheduleQuery for Temp {
+            fn handle(&self) -> QuerierResult {
+                let response = FeeDiscountScheduleResponse {
+                    fee_discount_schedule: Some(self.schedule.clone()),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { schedule }))
+    }
+
+    pub fn create_fee_discount_account_info_handler(tier_level: u64, tier_info: FeeDiscountTierInfo) -> Option<Box<dyn HandlesByAddressQuery>> {
+        struct Temp {
+            tier_level: u64,
+            tier_info: FeeDiscountTierInfo,
+        }
+        impl HandlesByAddressQuery for Temp {
+            fn handle(&self, _address: String) -> QuerierResult {
+                let response = FeeDiscountAccountInfoResponse {
+                    tier_level: self.tier_level,
+                    account_info: Some(self.tier_info.clone()),
+                    account_ttl: None,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { tier_level, tier_info }))
+    }
+
+    pub fn create_is_opted_out_of_rewards_handler(is_opted_out: bool) -> Option<Box<dyn HandlesByAddressQuery>> {
+        struct Temp {
+            is_opted_out: bool,
+        }
+        impl HandlesByAddressQuery for Temp {
+            fn handle(&self, _address: String) -> QuerierResult {
+                let response = IsOptedOutOfRewardsResponse {
+                    is_opted_out: self.is_opted_out,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { is_opted_out }))
+    }
+
+    /// Returns the points of each queried account, zero for accounts missing from `points`
+    pub fn create_trade_reward_points_handler(points: HashMap<String, FPDecimal>) -> Option<Box<dyn HandlesTradeRewardPointsQuery>> {
+        struct Temp {
+            points: HashMap<String, FPDecimal>,
+        }
+        impl HandlesTradeRewardPointsQuery for Temp {
+            fn handle(&self, accounts: Vec<String>, _pending_pool_timestamp: Option<i64>) -> QuerierResult {
+                let response = TradeRewardPointsResponse {
+                    account_trade_reward_points: accounts
+                        .iter()
+                        .map(|account| self.points.get(account).copied().unwrap_or(FPDecimal::ZERO))
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { points }))
+    }
+
+    pub fn create_trade_reward_campaign_handler(campaign: TradeRewardCampaignResponse) -> Option<Box<dyn HandlesTradeRewardCampaignQuery>> {
+        struct Temp {
+            campaign: TradeRewardCampaignResponse,
+        }
+        impl HandlesTradeRewardCampaignQuery for Temp {
+            fn handle(&self) -> QuerierResult {
+                SystemResult::Ok(ContractResult::from(to_json_binary(&self.campaign)))
+            }
+        }
+        Some(Box::new(Temp { campaign }))
+    }
+
     pub fn create_smart_query_handler(result: Result<Binary, SystemError>) -> Option<Box<dyn HandlesSmartQuery>> {
         struct Temp {
             result: Result<Binary, SystemError>,
@@ -1,32 +1,73 @@
+pub use authz::{
+    response::{Grant, GrantAuthorization, GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse, PageResponse},
+    types::{Authorization, BatchUpdateOrdersAuthz, ExchangeAuthz, PageRequest},
+};
 pub use exchange::{
+    batch::{BatchUpdateOrdersBuilder, BatchUpdateOrdersError},
+    binary_options_market::{calculate_binary_options_market_id, BinaryOptionsMarket},
     cancel::CancellationStrategy,
     derivative::{
-        DerivativeLimitOrder, DerivativeMarketOrder, DerivativeOrder, DerivativePosition, EffectivePosition, Position, TrimmedDerivativeLimitOrder,
+        DerivativeLimitOrder, DerivativeMarketOrder, DerivativeOrder, DerivativePosition, EffectivePosition, Position,
+        TrimmedDerivativeConditionalOrder, TrimmedDerivativeLimitOrder,
     },
     derivative_market::{
-        DerivativeMarket, FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
+        calculate_expiry_futures_market_id, calculate_perpetual_market_id, DerivativeMarket, FullDerivativeMarket, FullDerivativeMarketPerpetualInfo,
+        PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
+    },
+    fees::{FeeCalculator, TradeFee},
+    funding::{annualize_funding_rate, cap_funding_rate, funding_premium_twap, FundingProjection},
+    market::{GenericMarket, MarketStatus},
+    order::{
+        dec_from_proto, optional_dec_from_proto, optional_dec_to_proto, reconcile_cids, CidReconciliation, GenericOrder, GenericTrimmedOrder,
+        OrderData, OrderInfo, OrderMask, OrderSide, OrderType,
+    },
+    orderbook::{FillTarget, OrderbookFill, OrderbookSimulator},
+    reply::{
+        parse_reply, parse_sub_msg_response, BatchUpdateOrdersReply, DerivativeMarketOrderReply, ExchangeReply, LimitOrderReply, ReplyParseError,
+        SpotMarketOrderReply,
     },
-    market::MarketStatus,
-    order::{GenericOrder, GenericTrimmedOrder, OrderData, OrderInfo, OrderSide, OrderType},
     response::{
-        DerivativeMarketResponse, ExchangeParamsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
+        BinaryOptionsMarketResponse, BinaryOptionsMarketsResponse, DerivativeMarketResponse, DerivativeMarketsResponse,
+        DerivativeOrdersByHashesResponse, ExchangeParamsResponse, FeeDiscountAccountInfoResponse, FeeDiscountScheduleResponse,
+        IsOptedOutOfRewardsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
         PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
-        QueryDenomDecimalResponse, QueryDenomDecimalsResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarketResponse,
-        SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse,
-        TraderSpotOrdersResponse,
+        QueryDenomDecimalResponse, QueryDenomDecimalsResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, QueryOrderbookResponse,
+        SpotMarketResponse, SpotMarketsResponse, SpotOrdersByHashesResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse,
+        SubaccountPositionInMarketResponse, SubaccountPositionsResponse, TradeRewardCampaignResponse, TradeRewardPointsResponse,
+        TraderDerivativeConditionalOrdersResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
     },
+    rewards::{CampaignRewardPool, PointsMultiplier, TradingRewardCampaignBoostInfo, TradingRewardCampaignInfo},
+    risk::{check_initial_margin, initial_margin_requirement},
     spot::{MsgCreateSpotMarketOrderResponse, SpotLimitOrder, SpotMarketOrder, SpotOrder, TrimmedSpotLimitOrder},
-    spot_market::SpotMarket,
+    spot_market::{calculate_spot_market_id, SpotMarket},
     subaccount::{
-        addr_to_bech32, bech32_to_hex, checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, is_default_subaccount,
-        subaccount_id_to_ethereum_address, subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address,
+        addr_to_bech32, bech32_to_hex, canonical_to_ethereum_address, checked_address_to_subaccount_id, ethereum_address_to_canonical,
+        ethereum_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, is_default_subaccount, subaccount_id_to_ethereum_address,
+        subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address, subaccount_ids_for_address, try_addr_to_bech32,
+        try_address_to_subaccount_id, try_bech32_to_hex, try_subaccount_id_to_address, try_subaccount_id_to_ethereum_address,
+    },
+    types::{
+        DenomDecimals, Deposit, FeeDiscountSchedule, FeeDiscountTierInfo, FeeDiscountTierTTL, Hash, MarketId, MarketType, Params, PriceLevel,
+        ShortSubaccountId, SubaccountId,
+    },
+    validation::{
+        prepare_derivative_order, prepare_spot_order, round_order_info, validate_conditional_derivative_order, validate_derivative_order,
+        validate_spot_order, OrderExecution, OrderValidationError, RoundingMode,
     },
-    types::{DenomDecimals, Deposit, Hash, MarketId, MarketType, Params, PriceLevel, ShortSubaccountId, SubaccountId},
 };
 pub use oracle::{
-    response::{OraclePriceResponse, PythPriceResponse},
-    types::{OracleInfo, OracleType, PriceAttestation, PricePairState, PythStatus},
-    volatility::{MetadataStatistics, PriceRecord, TradeHistoryOptions, TradeRecord},
+    attestation::{PriceAttestationBuilder, PriceAttestationError},
+    composite::{compose_oracle_prices, CompositePrice, OracleHopPrice},
+    guard::{OraclePriceError, PriceGuard},
+    response::{HistoricalPriceRecordsResponse, OraclePriceResponse, ProviderPriceStateResponse, PythPriceResponse, StorkPriceStatesResponse},
+    types::{
+        AssetPair, OracleInfo, OracleType, PriceAttestation, PricePairState, PriceRecords, PriceState, PythPriceState, PythStatus,
+        SignedPriceOfAssetPair, StorkPriceState,
+    },
+    volatility::{
+        max_drawdown, realized_volatility, time_weighted_average_price, volume_weighted_average_price, MetadataStatistics, PriceRecord,
+        TimestampedPrice, TradeHistoryOptions, TradeRecord,
+    },
 };
 pub use wasmx::types::FundingMode;
 
@@ -37,11 +78,14 @@ pub use exchange_mock_querier::handlers::*;
 pub use exchange_mock_querier::*;
 
 pub use msg::{
-    cancel_derivative_order_msg, cancel_spot_order_msg, create_activate_contract_msg, create_batch_update_orders_msg, create_burn_tokens_msg,
-    create_deactivate_contract_msg, create_deposit_msg, create_derivative_market_order_msg, create_external_transfer_msg,
-    create_increase_position_margin_msg, create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg,
-    create_privileged_execute_contract_msg, create_relay_pyth_prices_msg, create_rewards_opt_out_msg, create_set_token_metadata_msg,
-    create_spot_market_order_msg, create_subaccount_transfer_msg, create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
+    cancel_binary_options_order_msg, cancel_derivative_conditional_order_msg, cancel_derivative_order_msg, cancel_derivative_resting_order_msg,
+    cancel_spot_order_msg, create_activate_contract_msg, create_batch_cancel_binary_options_orders_msg, create_batch_update_orders_msg,
+    create_binary_options_limit_order_msg, create_binary_options_market_order_msg, create_burn_tokens_msg, create_deactivate_contract_msg,
+    create_decrease_position_margin_msg, create_deposit_msg, create_derivative_limit_order_msg, create_derivative_market_order_msg,
+    create_external_transfer_msg, create_increase_position_margin_msg, create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg,
+    create_privileged_execute_contract_msg, create_relay_provider_prices_msg, create_relay_pyth_prices_msg, create_relay_stork_prices_msg,
+    create_rewards_opt_out_msg, create_set_token_metadata_msg, create_spot_limit_order_msg, create_spot_market_order_msg,
+    create_subaccount_transfer_msg, create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
 };
 
 pub use querier::InjectiveQuerier;
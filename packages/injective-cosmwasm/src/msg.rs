@@ -4,15 +4,19 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::exchange::{
-    derivative::{derivative_order_to_short, ShortDerivativeOrder},
-    order::{order_data_to_short, OrderData, ShortOrderData},
+    derivative::{derivative_order_to_short, ShortDerivativeOrder, TrimmedDerivativeConditionalOrder, TrimmedDerivativeLimitOrder},
+    order::{order_data_to_short, OrderData, OrderMask, ShortOrderData},
     privileged_action::coins_to_string,
     spot::{spot_order_to_short, ShortSpotOrder, SpotOrder},
     subaccount::{is_default_subaccount, subaccount_id_to_injective_address},
     types::{MarketId, SubaccountId},
 };
 use crate::InjectiveQueryWrapper;
-use crate::{exchange::derivative::DerivativeOrder, oracle::types::PriceAttestation, route::InjectiveRoute};
+use crate::{
+    exchange::derivative::DerivativeOrder,
+    oracle::types::{AssetPair, PriceAttestation},
+    route::InjectiveRoute,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -55,10 +59,18 @@ pub enum InjectiveMsg {
         destination_subaccount_id: SubaccountId,
         amount: Coin,
     },
+    CreateSpotLimitOrder {
+        sender: Addr,
+        order: SpotOrder,
+    },
     CreateSpotMarketOrder {
         sender: Addr,
         order: SpotOrder,
     },
+    CreateDerivativeLimitOrder {
+        sender: Addr,
+        order: DerivativeOrder,
+    },
     CreateDerivativeMarketOrder {
         sender: Addr,
         order: DerivativeOrder,
@@ -68,7 +80,7 @@ pub enum InjectiveMsg {
         market_id: MarketId,
         subaccount_id: SubaccountId,
         order_hash: String,
-        order_mask: i32,
+        order_mask: OrderMask,
     },
     CancelSpotOrder {
         sender: Addr,
@@ -76,6 +88,25 @@ pub enum InjectiveMsg {
         subaccount_id: SubaccountId,
         order_hash: String,
     },
+    CreateBinaryOptionsLimitOrder {
+        sender: Addr,
+        order: DerivativeOrder,
+    },
+    CreateBinaryOptionsMarketOrder {
+        sender: Addr,
+        order: DerivativeOrder,
+    },
+    CancelBinaryOptionsOrder {
+        sender: Addr,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        order_hash: String,
+        order_mask: OrderMask,
+    },
+    BatchCancelBinaryOptionsOrders {
+        sender: Addr,
+        data: Vec<OrderData>,
+    },
     IncreasePositionMargin {
         sender: Addr,
         source_subaccount_id: SubaccountId,
@@ -83,6 +114,13 @@ pub enum InjectiveMsg {
         market_id: MarketId,
         amount: FPDecimal,
     },
+    DecreasePositionMargin {
+        sender: Addr,
+        source_subaccount_id: SubaccountId,
+        destination_subaccount_id: SubaccountId,
+        market_id: MarketId,
+        amount: FPDecimal,
+    },
     PrivilegedExecuteContract {
         sender: Addr,
         funds: String, // TODO consider adding custom Vec<Coin> type with custom serializer using coins_to_string
@@ -112,6 +150,17 @@ pub enum InjectiveMsg {
         sender: Addr,
         price_attestations: Vec<PriceAttestation>,
     },
+    RelayStorkPrices {
+        sender: Addr,
+        asset_pairs: Vec<AssetPair>,
+    },
+    /// Prices are matched to symbols by position, the sender must be a relayer of the provider
+    RelayProviderPrices {
+        sender: Addr,
+        provider: String,
+        symbols: Vec<String>,
+        prices: Vec<FPDecimal>,
+    },
     CreateDenom {
         sender: String,
         subdenom: String,
@@ -249,6 +298,14 @@ pub fn create_external_transfer_msg(
     .into()])
 }
 
+pub fn create_spot_limit_order_msg(sender: Addr, order: SpotOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateSpotLimitOrder { sender, order },
+    }
+    .into()
+}
+
 pub fn create_spot_market_order_msg(sender: Addr, order: SpotOrder) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Exchange,
@@ -257,6 +314,14 @@ pub fn create_spot_market_order_msg(sender: Addr, order: SpotOrder) -> CosmosMsg
     .into()
 }
 
+pub fn create_derivative_limit_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateDerivativeLimitOrder { sender, order },
+    }
+    .into()
+}
+
 pub fn create_derivative_market_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Exchange,
@@ -283,7 +348,7 @@ pub fn cancel_derivative_order_msg(
     market_id: MarketId,
     subaccount_id: SubaccountId,
     order_hash: String,
-    order_mask: i32,
+    order_mask: OrderMask,
 ) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Exchange,
@@ -298,6 +363,76 @@ pub fn cancel_derivative_order_msg(
     .into()
 }
 
+/// Cancels a resting limit order by hash, using an order mask which matches regular orders on the order's side only
+pub fn cancel_derivative_resting_order_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    order: &TrimmedDerivativeLimitOrder,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    cancel_derivative_order_msg(
+        sender,
+        market_id,
+        subaccount_id,
+        order.order_hash.to_owned(),
+        OrderMask::regular(order.isBuy),
+    )
+}
+
+/// Cancels a conditional order by hash, using an order mask which matches conditional orders only
+pub fn cancel_derivative_conditional_order_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    order: &TrimmedDerivativeConditionalOrder,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    cancel_derivative_order_msg(sender, market_id, subaccount_id, order.order_hash.to_owned(), order.order_mask())
+}
+
+pub fn create_binary_options_limit_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateBinaryOptionsLimitOrder { sender, order },
+    }
+    .into()
+}
+
+pub fn create_binary_options_market_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateBinaryOptionsMarketOrder { sender, order },
+    }
+    .into()
+}
+
+pub fn cancel_binary_options_order_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    order_hash: String,
+    order_mask: OrderMask,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CancelBinaryOptionsOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash,
+            order_mask,
+        },
+    }
+    .into()
+}
+
+pub fn create_batch_cancel_binary_options_orders_msg(sender: Addr, data: Vec<OrderData>) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::BatchCancelBinaryOptionsOrders { sender, data },
+    }
+    .into()
+}
+
 pub fn create_increase_position_margin_msg(
     sender: Addr,
     source_subaccount_id: SubaccountId,
@@ -318,6 +453,26 @@ pub fn create_increase_position_margin_msg(
     .into()
 }
 
+pub fn create_decrease_position_margin_msg(
+    sender: Addr,
+    source_subaccount_id: SubaccountId,
+    destination_subaccount_id: SubaccountId,
+    market_id: MarketId,
+    amount: FPDecimal,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::DecreasePositionMargin {
+            sender,
+            source_subaccount_id,
+            destination_subaccount_id,
+            market_id,
+            amount,
+        },
+    }
+    .into()
+}
+
 pub fn create_privileged_execute_contract_msg(
     sender: Addr,
     funds: Vec<Coin>,
@@ -396,6 +551,30 @@ pub fn create_relay_pyth_prices_msg(sender: Addr, price_attestations: Vec<PriceA
     .into()
 }
 
+pub fn create_relay_stork_prices_msg(sender: Addr, asset_pairs: Vec<AssetPair>) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Oracle,
+        msg_data: InjectiveMsg::RelayStorkPrices { sender, asset_pairs },
+    }
+    .into()
+}
+
+/// Relays `(symbol, price)` pairs for a provider oracle
+pub fn create_relay_provider_prices_msg(sender: Addr, provider: String, prices: Vec<(String, FPDecimal)>) -> CosmosMsg<InjectiveMsgWrapper> {
+    let (symbols, prices) = prices.into_iter().unzip();
+
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Oracle,
+        msg_data: InjectiveMsg::RelayProviderPrices {
+            sender,
+            provider,
+            symbols,
+            prices,
+        },
+    }
+    .into()
+}
+
 pub fn create_mint_tokens_msg(sender: Addr, amount: Coin, mint_to: String) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Tokenfactory,
@@ -472,3 +651,174 @@ pub fn create_deactivate_contract_msg(sender: Addr, contract_address: Addr) -> C
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, CosmosMsg};
+    use injective_math::FPDecimal;
+
+    use crate::{
+        create_decrease_position_margin_msg, create_derivative_limit_order_msg, create_relay_provider_prices_msg, create_relay_stork_prices_msg,
+        create_spot_limit_order_msg, AssetPair, DerivativeOrder, InjectiveMsg, InjectiveMsgWrapper, MarketId, OrderMask, OrderType,
+        SignedPriceOfAssetPair, SpotOrder, SubaccountId, TEST_MARKET_ID_1,
+    };
+
+    const SENDER: &str = "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv";
+    const SUBACCOUNT_ID: &str = "0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000000";
+
+    fn unwrap_custom(msg: CosmosMsg<InjectiveMsgWrapper>) -> InjectiveMsgWrapper {
+        match msg {
+            CosmosMsg::Custom(wrapper) => wrapper,
+            _ => panic!("expected custom injective message"),
+        }
+    }
+
+    #[test]
+    fn spot_limit_order_msg_serialization() {
+        let order = SpotOrder::new(
+            FPDecimal::must_from_str("1.5"),
+            FPDecimal::must_from_str("10"),
+            OrderType::BuyPo,
+            &MarketId::unchecked(TEST_MARKET_ID_1),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            Some("cid-1".to_string()),
+        );
+        let wrapper = unwrap_custom(create_spot_limit_order_msg(Addr::unchecked(SENDER), order.clone()));
+
+        assert_eq!(
+            wrapper.msg_data,
+            InjectiveMsg::CreateSpotLimitOrder {
+                sender: Addr::unchecked(SENDER),
+                order
+            }
+        );
+        assert_eq!(
+            serde_json_wasm::to_string(&wrapper).unwrap(),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"create_spot_limit_order":{{"sender":"{SENDER}","order":{{"market_id":"{TEST_MARKET_ID_1}","order_info":{{"subaccount_id":"{SUBACCOUNT_ID}","fee_recipient":null,"price":"1.5","quantity":"10","cid":"cid-1"}},"order_type":7,"trigger_price":null}}}}}}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn derivative_limit_order_msg_serialization() {
+        let order = DerivativeOrder::new(
+            FPDecimal::must_from_str("2"),
+            FPDecimal::must_from_str("3"),
+            FPDecimal::must_from_str("0.6"),
+            OrderType::Sell,
+            MarketId::unchecked(TEST_MARKET_ID_1),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            Some(Addr::unchecked(SENDER)),
+            None,
+        );
+        let wrapper = unwrap_custom(create_derivative_limit_order_msg(Addr::unchecked(SENDER), order));
+
+        assert_eq!(
+            serde_json_wasm::to_string(&wrapper).unwrap(),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"create_derivative_limit_order":{{"sender":"{SENDER}","order":{{"market_id":"{TEST_MARKET_ID_1}","order_info":{{"subaccount_id":"{SUBACCOUNT_ID}","fee_recipient":"{SENDER}","price":"2","quantity":"3","cid":null}},"order_type":2,"margin":"0.6","trigger_price":null}}}}}}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn decrease_position_margin_msg_serialization() {
+        let wrapper = unwrap_custom(create_decrease_position_margin_msg(
+            Addr::unchecked(SENDER),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            MarketId::unchecked(TEST_MARKET_ID_1),
+            FPDecimal::must_from_str("12.5"),
+        ));
+
+        assert_eq!(
+            serde_json_wasm::to_string(&wrapper).unwrap(),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"decrease_position_margin":{{"sender":"{SENDER}","source_subaccount_id":"{SUBACCOUNT_ID}","destination_subaccount_id":"{SUBACCOUNT_ID}","market_id":"{TEST_MARKET_ID_1}","amount":"12.5"}}}}}}"#
+            )
+        );
+
+        let decoded: InjectiveMsgWrapper = serde_json_wasm::from_str(&serde_json_wasm::to_string(&wrapper).unwrap()).unwrap();
+        assert_eq!(decoded, wrapper);
+    }
+
+    #[test]
+    fn cancels_queried_conditional_orders_with_conditional_mask() {
+        use crate::{cancel_derivative_conditional_order_msg, create_trader_derivative_conditional_orders_handler, inj_mock_deps, InjectiveQuerier};
+
+        let order: crate::TrimmedDerivativeConditionalOrder = serde_json_wasm::from_str(
+            r#"{"price":"9.5","quantity":"2","margin":"0","triggerPrice":"9","isBuy":false,"isLimit":true,"order_hash":"0x01","cid":"stop-1"}"#,
+        )
+        .unwrap();
+        let deps = inj_mock_deps(|querier| {
+            querier.trader_derivative_conditional_orders_response_handler = create_trader_derivative_conditional_orders_handler(vec![order])
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_1);
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT_ID);
+        let orders = querier
+            .query_trader_derivative_conditional_orders(&market_id, &subaccount_id)
+            .unwrap()
+            .orders;
+        assert_eq!(orders.len(), 1);
+        assert!(orders[0].is_reduce_only());
+
+        let wrapper = unwrap_custom(cancel_derivative_conditional_order_msg(
+            Addr::unchecked(SENDER),
+            market_id.clone(),
+            subaccount_id.clone(),
+            &orders[0],
+        ));
+        assert_eq!(
+            wrapper.msg_data,
+            InjectiveMsg::CancelDerivativeOrder {
+                sender: Addr::unchecked(SENDER),
+                market_id,
+                subaccount_id,
+                order_hash: "0x01".to_string(),
+                order_mask: OrderMask::CONDITIONAL | OrderMask::TYPE_LIMIT,
+            }
+        );
+    }
+
+    #[test]
+    fn relay_oracle_prices_msg_serialization() {
+        let wrapper = unwrap_custom(create_relay_provider_prices_msg(
+            Addr::unchecked(SENDER),
+            "provider".to_string(),
+            vec![
+                ("INJ".to_string(), FPDecimal::must_from_str("25.5")),
+                ("ATOM".to_string(), FPDecimal::must_from_str("8")),
+            ],
+        ));
+        assert_eq!(
+            serde_json_wasm::to_string(&wrapper).unwrap(),
+            format!(
+                r#"{{"route":"oracle","msg_data":{{"relay_provider_prices":{{"sender":"{SENDER}","provider":"provider","symbols":["INJ","ATOM"],"prices":["25.5","8"]}}}}}}"#
+            )
+        );
+
+        let wrapper = unwrap_custom(create_relay_stork_prices_msg(
+            Addr::unchecked(SENDER),
+            vec![AssetPair {
+                asset_id: "BTCUSD".to_string(),
+                signed_prices: vec![SignedPriceOfAssetPair {
+                    publisher_key: "0x01".to_string(),
+                    timestamp: 1700000000000000000,
+                    price: FPDecimal::must_from_str("60000"),
+                    signature: vec![1u8, 2, 3].into(),
+                }],
+            }],
+        ));
+        assert_eq!(
+            serde_json_wasm::to_string(&wrapper).unwrap(),
+            format!(
+                r#"{{"route":"oracle","msg_data":{{"relay_stork_prices":{{"sender":"{SENDER}","asset_pairs":[{{"asset_id":"BTCUSD","signed_prices":[{{"publisher_key":"0x01","timestamp":1700000000000000000,"price":"60000","signature":"AQID"}}]}}]}}}}}}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use cosmwasm_std::StdError;
+use injective_math::FPDecimal;
+use thiserror::Error;
+
+use crate::exchange::types::Hash;
+use crate::oracle::types::{PriceAttestation, PythStatus};
+
+/// Largest exponent magnitude that can be represented with the 18 decimals of `FPDecimal`
+const MAX_EXPO: u32 = 18;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PriceAttestationError {
+    #[error("Exponent {0} is out of range, max magnitude is 18")]
+    UnsupportedExponent(i32),
+
+    #[error("{value} can't be represented as an integer with exponent {expo}")]
+    PrecisionLoss { value: FPDecimal, expo: i32 },
+
+    #[error("{value} is out of range with exponent {expo}")]
+    OutOfRange { value: FPDecimal, expo: i32 },
+
+    #[error("Price {price_id} is {status:?}, only trading prices can be relayed")]
+    NotTrading { price_id: Hash, status: PythStatus },
+
+    #[error("Price {price_id} was published at {publish_time}, which is not after the last publish time {last_publish_time}")]
+    StalePublishTime {
+        price_id: Hash,
+        publish_time: i64,
+        last_publish_time: i64,
+    },
+}
+
+impl From<PriceAttestationError> for StdError {
+    fn from(err: PriceAttestationError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+impl PriceAttestation {
+    pub fn builder(price_id: Hash, expo: i32) -> PriceAttestationBuilder {
+        PriceAttestationBuilder::new(price_id, expo)
+    }
+
+    /// Price as a decimal, i.e. `price * 10^expo`
+    pub fn to_price(&self) -> Result<FPDecimal, PriceAttestationError> {
+        from_exponent(i128::from(self.price), self.expo)
+    }
+
+    pub fn to_conf(&self) -> Result<FPDecimal, PriceAttestationError> {
+        from_exponent(i128::from(self.conf), self.expo)
+    }
+
+    pub fn to_ema_price(&self) -> Result<FPDecimal, PriceAttestationError> {
+        from_exponent(i128::from(self.ema_price), self.expo)
+    }
+
+    pub fn to_ema_conf(&self) -> Result<FPDecimal, PriceAttestationError> {
+        from_exponent(i128::from(self.ema_conf), self.expo)
+    }
+
+    /// Checks the price is trading and, if a previous price is known, published after it
+    pub fn validate(&self, last_publish_time: Option<i64>) -> Result<(), PriceAttestationError> {
+        if self.status != PythStatus::Trading {
+            return Err(PriceAttestationError::NotTrading {
+                price_id: self.price_id.clone(),
+                status: self.status,
+            });
+        }
+
+        match last_publish_time {
+            Some(last_publish_time) if self.publish_time <= last_publish_time => Err(PriceAttestationError::StalePublishTime {
+                price_id: self.price_id.clone(),
+                publish_time: self.publish_time,
+                last_publish_time,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Builds a `PriceAttestation` from decimal prices, which are converted to integers with the attestation's exponent.
+/// The EMA price and confidence default to the price and confidence, the attestation time to the publish time.
+#[derive(Clone, Debug)]
+pub struct PriceAttestationBuilder {
+    price_id: Hash,
+    expo: i32,
+    product_id: String,
+    price: FPDecimal,
+    conf: FPDecimal,
+    ema_price: Option<FPDecimal>,
+    ema_conf: Option<FPDecimal>,
+    status: PythStatus,
+    num_publishers: u32,
+    max_num_publishers: u32,
+    publish_time: i64,
+    attestation_time: Option<i64>,
+    last_publish_time: Option<i64>,
+}
+
+impl PriceAttestationBuilder {
+    pub fn new(price_id: Hash, expo: i32) -> Self {
+        PriceAttestationBuilder {
+            price_id,
+            expo,
+            product_id: String::new(),
+            price: FPDecimal::ZERO,
+            conf: FPDecimal::ZERO,
+            ema_price: None,
+            ema_conf: None,
+            status: PythStatus::Trading,
+            num_publishers: 0,
+            max_num_publishers: 0,
+            publish_time: 0,
+            attestation_time: None,
+            last_publish_time: None,
+        }
+    }
+
+    pub fn product_id(mut self, product_id: impl Into<String>) -> Self {
+        self.product_id = product_id.into();
+        self
+    }
+
+    pub fn price(mut self, price: FPDecimal, conf: FPDecimal) -> Self {
+        self.price = price;
+        self.conf = conf;
+        self
+    }
+
+    pub fn ema_price(mut self, ema_price: FPDecimal, ema_conf: FPDecimal) -> Self {
+        self.ema_price = Some(ema_price);
+        self.ema_conf = Some(ema_conf);
+        self
+    }
+
+    pub fn status(mut self, status: PythStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn publishers(mut self, num_publishers: u32, max_num_publishers: u32) -> Self {
+        self.num_publishers = num_publishers;
+        self.max_num_publishers = max_num_publishers;
+        self
+    }
+
+    pub fn publish_time(mut self, publish_time: i64) -> Self {
+        self.publish_time = publish_time;
+        self
+    }
+
+    pub fn attestation_time(mut self, attestation_time: i64) -> Self {
+        self.attestation_time = Some(attestation_time);
+        self
+    }
+
+    /// Requires the publish time to be after the last relayed publish time, e.g. from `query_pyth_price`
+    pub fn after(mut self, last_publish_time: i64) -> Self {
+        self.last_publish_time = Some(last_publish_time);
+        self
+    }
+
+    pub fn build(self) -> Result<PriceAttestation, PriceAttestationError> {
+        let expo = self.expo;
+        let ema_price = self.ema_price.unwrap_or(self.price);
+        let ema_conf = self.ema_conf.unwrap_or(self.conf);
+        let attestation = PriceAttestation {
+            product_id: self.product_id,
+            price_id: self.price_id,
+            price: to_exponent(self.price, expo)?,
+            conf: to_unsigned_exponent(self.conf, expo)?,
+            expo,
+            ema_price: to_exponent(ema_price, expo)?,
+            ema_conf: to_unsigned_exponent(ema_conf, expo)?,
+            status: self.status,
+            num_publishers: self.num_publishers,
+            max_num_publishers: self.max_num_publishers,
+            attestation_time: self.attestation_time.unwrap_or(self.publish_time),
+            publish_time: self.publish_time,
+        };
+
+        attestation.validate(self.last_publish_time)?;
+        Ok(attestation)
+    }
+}
+
+fn pow10(expo: i32) -> Result<FPDecimal, PriceAttestationError> {
+    if expo.unsigned_abs() > MAX_EXPO {
+        return Err(PriceAttestationError::UnsupportedExponent(expo));
+    }
+    Ok(FPDecimal::from(10u128.pow(expo.unsigned_abs())))
+}
+
+fn from_exponent(value: i128, expo: i32) -> Result<FPDecimal, PriceAttestationError> {
+    let scale = pow10(expo)?;
+    if expo < 0 {
+        Ok(FPDecimal::from(value) / scale)
+    } else {
+        Ok(FPDecimal::from(value) * scale)
+    }
+}
+
+/// Integer `value * 10^-expo`, failing if `value` has more decimals than the exponent allows
+fn to_exponent(value: FPDecimal, expo: i32) -> Result<i64, PriceAttestationError> {
+    let scale = pow10(expo)?;
+    let scaled = if expo < 0 { value * scale } else { value / scale };
+    if !scaled.is_int() {
+        return Err(PriceAttestationError::PrecisionLoss { value, expo });
+    }
+    if scaled.abs() > FPDecimal::from(i128::from(i64::MAX)) {
+        return Err(PriceAttestationError::OutOfRange { value, expo });
+    }
+
+    let magnitude = u128::from(scaled.abs()) as i64;
+    Ok(if scaled.is_negative() { -magnitude } else { magnitude })
+}
+
+fn to_unsigned_exponent(value: FPDecimal, expo: i32) -> Result<u64, PriceAttestationError> {
+    u64::try_from(to_exponent(value, expo)?).map_err(|_| PriceAttestationError::OutOfRange { value, expo })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRICE_ID: &str = "0xff0ec26442c57d7456695b843694e7379b15cf1b250b27e0e47e657f1955aaff";
+
+    fn price_id() -> Hash {
+        Hash::from_hex(PRICE_ID.trim_start_matches("0x")).unwrap()
+    }
+
+    #[test]
+    fn builds_attestation_from_decimal_prices() {
+        let attestation = PriceAttestation::builder(price_id(), -8)
+            .product_id("INJ/USD")
+            .price(FPDecimal::must_from_str("25.5"), FPDecimal::must_from_str("0.01"))
+            .publishers(10, 20)
+            .publish_time(1700000000)
+            .after(1699999999)
+            .build()
+            .unwrap();
+
+        assert_eq!(attestation.price, 2_550_000_000);
+        assert_eq!(attestation.conf, 1_000_000);
+        assert_eq!(attestation.ema_price, 2_550_000_000);
+        assert_eq!(attestation.attestation_time, 1700000000);
+        assert_eq!(attestation.to_price(), Ok(FPDecimal::must_from_str("25.5")));
+        assert_eq!(attestation.to_conf(), Ok(FPDecimal::must_from_str("0.01")));
+
+        let json = serde_json_wasm::to_string(&attestation).unwrap();
+        assert!(json.contains(&format!(r#""price_id":"{PRICE_ID}""#)));
+        assert_eq!(serde_json_wasm::from_str::<PriceAttestation>(&json).unwrap(), attestation);
+
+        let attestation = PriceAttestation::builder(price_id(), 2)
+            .price(FPDecimal::must_from_str("1200"), FPDecimal::ZERO)
+            .build()
+            .unwrap();
+        assert_eq!(attestation.price, 12);
+        assert_eq!(attestation.to_price(), Ok(FPDecimal::must_from_str("1200")));
+    }
+
+    #[test]
+    fn rejects_invalid_attestations() {
+        let builder = PriceAttestation::builder(price_id(), -2).publish_time(1700000000);
+
+        assert_eq!(
+            builder.clone().price(FPDecimal::must_from_str("1.005"), FPDecimal::ZERO).build(),
+            Err(PriceAttestationError::PrecisionLoss {
+                value: FPDecimal::must_from_str("1.005"),
+                expo: -2,
+            })
+        );
+        assert_eq!(
+            builder.clone().price(FPDecimal::ONE, FPDecimal::must_from_str("-0.01")).build(),
+            Err(PriceAttestationError::OutOfRange {
+                value: FPDecimal::must_from_str("-0.01"),
+                expo: -2,
+            })
+        );
+        assert_eq!(
+            builder.clone().status(PythStatus::Halted).build(),
+            Err(PriceAttestationError::NotTrading {
+                price_id: price_id(),
+                status: PythStatus::Halted,
+            })
+        );
+        assert_eq!(
+            builder.after(1700000000).build(),
+            Err(PriceAttestationError::StalePublishTime {
+                price_id: price_id(),
+                publish_time: 1700000000,
+                last_publish_time: 1700000000,
+            })
+        );
+        assert_eq!(
+            PriceAttestation::builder(price_id(), -19).build(),
+            Err(PriceAttestationError::UnsupportedExponent(-19))
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use cosmwasm_std::StdError;
+use injective_math::FPDecimal;
+
+use crate::oracle::{guard::OraclePriceError, types::ScalingOptions};
+
+/// Price of a single hop of an oracle path, as quoted by the oracle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OracleHopPrice {
+    pub feed: String,
+    pub price: FPDecimal,
+    /// Confidence interval, `None` for oracles that don't report one
+    pub conf: Option<FPDecimal>,
+    pub timestamp: i64,
+    pub scale_factor: u32,
+    /// Whether the hop is traversed from quote to base
+    pub invert: bool,
+}
+
+/// Price obtained by multiplying the prices along an oracle path.
+/// The confidence only covers hops whose oracle reports one, the other hops are listed in `hops_without_conf`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompositePrice {
+    pub price: FPDecimal,
+    /// Sum of the relative confidence of the hops reporting one, scaled to the composite price
+    pub conf: FPDecimal,
+    /// Oldest timestamp along the path
+    pub timestamp: i64,
+    /// Feeds of the hops that don't report a confidence, the confidence is understated if any
+    pub hops_without_conf: Vec<String>,
+}
+
+/// Multiplies the hop prices, inverting hops as requested, after scaling each price by `10^scale_factor`.
+/// The scaling options convert the composite price to chain format, i.e. `price * 10^quote_decimals / 10^base_decimals`.
+pub fn compose_oracle_prices(hops: &[OracleHopPrice], scaling_options: Option<&ScalingOptions>) -> Result<CompositePrice, OraclePriceError> {
+    if hops.is_empty() {
+        return Err(OraclePriceError::EmptyOraclePath);
+    }
+
+    let mut price = FPDecimal::ONE;
+    let mut relative_conf = FPDecimal::ZERO;
+    let mut timestamp = i64::MAX;
+    let mut hops_without_conf = vec![];
+    for hop in hops {
+        let scale = pow10(hop.scale_factor)?;
+        let hop_price = hop.price * scale;
+        if hop_price.is_zero() || hop_price.is_negative() {
+            return Err(OraclePriceError::NonPositivePrice {
+                feed: hop.feed.to_owned(),
+                price: hop_price,
+            });
+        }
+
+        // relative confidence is the same for the price and its inverse
+        match hop.conf {
+            Some(conf) => relative_conf += conf * scale / hop_price,
+            None => hops_without_conf.push(hop.feed.to_owned()),
+        }
+        price = if hop.invert { price / hop_price } else { price * hop_price };
+        timestamp = timestamp.min(hop.timestamp);
+    }
+
+    if let Some(scaling_options) = scaling_options {
+        price = price * pow10(u32::from(scaling_options.quote_decimals))? / pow10(u32::from(scaling_options.base_decimals))?;
+    }
+
+    Ok(CompositePrice {
+        price,
+        conf: price * relative_conf,
+        timestamp,
+        hops_without_conf,
+    })
+}
+
+fn pow10(exponent: u32) -> Result<FPDecimal, OraclePriceError> {
+    10u128
+        .checked_pow(exponent)
+        .map(FPDecimal::from)
+        .ok_or_else(|| StdError::generic_err(format!("Scaling by 10^{exponent} overflows")).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange_mock_querier::handlers::{create_oracle_query_handler, create_pyth_price_handler};
+    use crate::oracle::types::{OracleInfo, OracleType, PriceState, PythPriceState};
+    use crate::test_helpers::testing_helpers::inj_mock_deps;
+    use crate::InjectiveQuerier;
+
+    fn hop(price: &str, conf: Option<&str>, timestamp: i64, scale_factor: u32, invert: bool) -> OracleHopPrice {
+        OracleHopPrice {
+            feed: "feed".to_string(),
+            price: FPDecimal::must_from_str(price),
+            conf: conf.map(FPDecimal::must_from_str),
+            timestamp,
+            scale_factor,
+            invert,
+        }
+    }
+
+    #[test]
+    fn composes_hop_prices() {
+        // ATOM/INJ from ATOM/USD and INJ/USD
+        let composite = compose_oracle_prices(&[hop("10", Some("0.1"), 200, 0, false), hop("25", Some("0.5"), 100, 0, true)], None).unwrap();
+        assert_eq!(composite.price, FPDecimal::must_from_str("0.4"));
+        assert_eq!(composite.conf, FPDecimal::must_from_str("0.012"));
+        assert_eq!(composite.timestamp, 100);
+        assert!(composite.hops_without_conf.is_empty());
+
+        let scaling = ScalingOptions {
+            base_decimals: 18,
+            quote_decimals: 6,
+        };
+        let composite = compose_oracle_prices(&[hop("0.000025", None, 100, 6, false)], Some(&scaling)).unwrap();
+        assert_eq!(composite.price, FPDecimal::must_from_str("0.000000000025"));
+        assert_eq!(composite.hops_without_conf, vec!["feed".to_string()]);
+
+        assert_eq!(compose_oracle_prices(&[], None), Err(OraclePriceError::EmptyOraclePath));
+        assert!(matches!(
+            compose_oracle_prices(&[hop("0", None, 100, 0, true)], None),
+            Err(OraclePriceError::NonPositivePrice { .. })
+        ));
+    }
+
+    #[test]
+    fn queries_composite_price_across_oracles() {
+        let deps = inj_mock_deps(|querier| {
+            querier.pyth_price_response_handler = create_pyth_price_handler(PythPriceState {
+                price_id: "0x01".to_string(),
+                ema_price: FPDecimal::must_from_str("25"),
+                ema_conf: FPDecimal::ZERO,
+                conf: FPDecimal::must_from_str("0.25"),
+                publish_time: 1700000100,
+                price_state: PriceState {
+                    price: FPDecimal::must_from_str("25"),
+                    cumulative_price: FPDecimal::ZERO,
+                    timestamp: 1700000100,
+                },
+            });
+            querier.oracle_price_response_handler = create_oracle_query_handler(
+                FPDecimal::must_from_str("2"),
+                FPDecimal::must_from_str("2"),
+                FPDecimal::ONE,
+                FPDecimal::ZERO,
+                FPDecimal::ZERO,
+                1700000050,
+                1700000000,
+            );
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let path = [
+            (
+                OracleInfo {
+                    symbol: "0x01".to_string(),
+                    oracle_type: OracleType::Pyth,
+                    scale_factor: 0,
+                },
+                false,
+            ),
+            (
+                OracleInfo {
+                    symbol: "usdt/usd".to_string(),
+                    oracle_type: OracleType::PriceFeed,
+                    scale_factor: 0,
+                },
+                true,
+            ),
+        ];
+        let composite = querier.query_composite_oracle_price(&path, None).unwrap();
+        assert_eq!(
+            composite,
+            CompositePrice {
+                price: FPDecimal::must_from_str("12.5"),
+                conf: FPDecimal::must_from_str("0.125"),
+                timestamp: 1700000000,
+                hops_without_conf: vec!["usdt/usd".to_string()],
+            }
+        );
+
+        // pair based oracles need an explicit quote
+        let path = [(
+            OracleInfo {
+                symbol: "usdt".to_string(),
+                oracle_type: OracleType::PriceFeed,
+                scale_factor: 0,
+            },
+            false,
+        )];
+        assert_eq!(
+            querier.query_composite_oracle_price(&path, None),
+            Err(OraclePriceError::InvalidOracleSymbol("usdt".to_string()))
+        );
+    }
+}
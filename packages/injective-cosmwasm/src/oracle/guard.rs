@@ -0,0 +1,333 @@
+use cosmwasm_std::{StdError, Timestamp};
+use injective_math::FPDecimal;
+use thiserror::Error;
+
+use crate::oracle::types::{PricePairState, PythPriceState};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum OraclePriceError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("No price available for {0}")]
+    PriceNotFound(String),
+
+    #[error("Price of {feed} must be positive, got {price}")]
+    NonPositivePrice { feed: String, price: FPDecimal },
+
+    #[error("Price of {feed} is {age} seconds old, max age is {max_age} seconds")]
+    StalePrice { feed: String, age: u64, max_age: u64 },
+
+    #[error("Price of {feed} is {ahead} seconds ahead of the block time, max skew is {max_skew} seconds")]
+    FutureTimestamp { feed: String, ahead: u64, max_skew: u64 },
+
+    #[error("Price of {feed} can't be checked against {bound}, the oracle doesn't report it")]
+    UnsupportedBound { feed: String, bound: String },
+
+    #[error("Confidence of {feed} is {ratio} of the price, max is {max_ratio}")]
+    ConfidenceTooWide { feed: String, ratio: FPDecimal, max_ratio: FPDecimal },
+
+    #[error("Price of {feed} deviates {deviation} from its EMA, max is {max_deviation}")]
+    EmaDeviationTooLarge {
+        feed: String,
+        deviation: FPDecimal,
+        max_deviation: FPDecimal,
+    },
+
+    #[error("Oracle path has no hops")]
+    EmptyOraclePath,
+
+    #[error("Oracle symbol {0} must be written as base/quote")]
+    InvalidOracleSymbol(String),
+}
+
+impl From<OraclePriceError> for StdError {
+    fn from(err: OraclePriceError) -> Self {
+        match err {
+            OraclePriceError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Bounds an oracle price must satisfy before it is used. Ratios are relative, e.g. 0.01 for 1%.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceGuard {
+    /// Max seconds between the price timestamp and the block time
+    pub max_age: u64,
+    /// Max confidence interval relative to the price, only available for Pyth prices
+    pub max_confidence_ratio: Option<FPDecimal>,
+    /// Max distance of the price from its EMA relative to the EMA, only available for Pyth prices
+    pub max_ema_deviation: Option<FPDecimal>,
+    /// Max seconds the price timestamp may be ahead of the block time
+    pub max_future_skew: u64,
+}
+
+impl PriceGuard {
+    pub fn new(max_age: u64) -> Self {
+        PriceGuard {
+            max_age,
+            max_confidence_ratio: None,
+            max_ema_deviation: None,
+            max_future_skew: 0,
+        }
+    }
+
+    pub fn with_max_confidence_ratio(mut self, max_confidence_ratio: FPDecimal) -> Self {
+        self.max_confidence_ratio = Some(max_confidence_ratio);
+        self
+    }
+
+    pub fn with_max_ema_deviation(mut self, max_ema_deviation: FPDecimal) -> Self {
+        self.max_ema_deviation = Some(max_ema_deviation);
+        self
+    }
+
+    pub fn with_max_future_skew(mut self, max_future_skew: u64) -> Self {
+        self.max_future_skew = max_future_skew;
+        self
+    }
+
+    /// Checks the price against the publish time, confidence and EMA bounds
+    pub fn check_pyth_price(&self, state: &PythPriceState, block_time: Timestamp) -> Result<(), OraclePriceError> {
+        let feed = &state.price_id;
+        let price = state.price_state.price;
+        self.check_price(feed, price)?;
+        self.check_age(feed, state.publish_time, block_time)?;
+
+        if let Some(max_ratio) = self.max_confidence_ratio {
+            let ratio = state.conf / price;
+            if ratio > max_ratio {
+                return Err(OraclePriceError::ConfidenceTooWide {
+                    feed: feed.to_owned(),
+                    ratio,
+                    max_ratio,
+                });
+            }
+        }
+
+        if let Some(max_deviation) = self.max_ema_deviation {
+            self.check_price(feed, state.ema_price)?;
+            let deviation = (price - state.ema_price).abs() / state.ema_price;
+            if deviation > max_deviation {
+                return Err(OraclePriceError::EmaDeviationTooLarge {
+                    feed: feed.to_owned(),
+                    deviation,
+                    max_deviation,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the pair price against the base and quote timestamps. Price pairs carry no confidence or EMA,
+    /// so a guard with either bound set is rejected instead of silently passing.
+    pub fn check_price_pair(&self, feed: &str, state: &PricePairState, block_time: Timestamp) -> Result<(), OraclePriceError> {
+        let unsupported_bound = match (self.max_confidence_ratio, self.max_ema_deviation) {
+            (Some(_), _) => Some("max_confidence_ratio"),
+            (None, Some(_)) => Some("max_ema_deviation"),
+            (None, None) => None,
+        };
+        if let Some(bound) = unsupported_bound {
+            return Err(OraclePriceError::UnsupportedBound {
+                feed: feed.to_owned(),
+                bound: bound.to_string(),
+            });
+        }
+
+        self.check_price(feed, state.pair_price)?;
+        self.check_age(feed, state.base_timestamp, block_time)?;
+        self.check_age(feed, state.quote_timestamp, block_time)
+    }
+
+    fn check_price(&self, feed: &str, price: FPDecimal) -> Result<(), OraclePriceError> {
+        if price.is_zero() || price.is_negative() {
+            return Err(OraclePriceError::NonPositivePrice {
+                feed: feed.to_owned(),
+                price,
+            });
+        }
+        Ok(())
+    }
+
+    /// Timestamps ahead of the block time are only accepted within `max_future_skew`
+    fn check_age(&self, feed: &str, timestamp: i64, block_time: Timestamp) -> Result<(), OraclePriceError> {
+        let elapsed = i128::from(block_time.seconds()) - i128::from(timestamp);
+        if elapsed < 0 {
+            let ahead = u64::try_from(-elapsed).unwrap_or(u64::MAX);
+            if ahead > self.max_future_skew {
+                return Err(OraclePriceError::FutureTimestamp {
+                    feed: feed.to_owned(),
+                    ahead,
+                    max_skew: self.max_future_skew,
+                });
+            }
+            return Ok(());
+        }
+
+        let age = u64::try_from(elapsed).unwrap_or(u64::MAX);
+        if age > self.max_age {
+            return Err(OraclePriceError::StalePrice {
+                feed: feed.to_owned(),
+                age,
+                max_age: self.max_age,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+
+    use super::*;
+    use crate::exchange_mock_querier::handlers::{create_oracle_query_handler, create_pyth_price_handler};
+    use crate::oracle::types::{OracleType, PriceState};
+    use crate::test_helpers::testing_helpers::inj_mock_deps;
+    use crate::InjectiveQuerier;
+
+    const PRICE_ID: &str = "0xff0ec26442c57d7456695b843694e7379b15cf1b250b27e0e47e657f1955aaff";
+
+    fn pyth_price(price: &str, conf: &str, ema_price: &str, publish_time: i64) -> PythPriceState {
+        PythPriceState {
+            price_id: PRICE_ID.to_string(),
+            ema_price: FPDecimal::must_from_str(ema_price),
+            ema_conf: FPDecimal::ZERO,
+            conf: FPDecimal::must_from_str(conf),
+            publish_time,
+            price_state: PriceState {
+                price: FPDecimal::must_from_str(price),
+                cumulative_price: FPDecimal::ZERO,
+                timestamp: publish_time,
+            },
+        }
+    }
+
+    #[test]
+    fn checks_pyth_price_bounds() {
+        let now = mock_env().block.time;
+        let published = now.seconds() as i64 - 30;
+        let guard = PriceGuard::new(60)
+            .with_max_confidence_ratio(FPDecimal::must_from_str("0.01"))
+            .with_max_ema_deviation(FPDecimal::must_from_str("0.05"));
+
+        assert_eq!(guard.check_pyth_price(&pyth_price("100", "0.5", "98", published), now), Ok(()));
+        // published after the block time
+        assert_eq!(
+            guard.check_pyth_price(&pyth_price("100", "0.5", "98", published + 60), now),
+            Err(OraclePriceError::FutureTimestamp {
+                feed: PRICE_ID.to_string(),
+                ahead: 30,
+                max_skew: 0,
+            })
+        );
+        assert_eq!(
+            guard
+                .clone()
+                .with_max_future_skew(30)
+                .check_pyth_price(&pyth_price("100", "0.5", "98", published + 60), now),
+            Ok(())
+        );
+
+        assert_eq!(
+            guard.check_pyth_price(&pyth_price("100", "0.5", "98", published - 31), now),
+            Err(OraclePriceError::StalePrice {
+                feed: PRICE_ID.to_string(),
+                age: 61,
+                max_age: 60,
+            })
+        );
+        assert_eq!(
+            guard.check_pyth_price(&pyth_price("100", "2", "98", published), now),
+            Err(OraclePriceError::ConfidenceTooWide {
+                feed: PRICE_ID.to_string(),
+                ratio: FPDecimal::must_from_str("0.02"),
+                max_ratio: FPDecimal::must_from_str("0.01"),
+            })
+        );
+        assert_eq!(
+            guard.check_pyth_price(&pyth_price("100", "0.5", "80", published), now),
+            Err(OraclePriceError::EmaDeviationTooLarge {
+                feed: PRICE_ID.to_string(),
+                deviation: FPDecimal::must_from_str("0.25"),
+                max_deviation: FPDecimal::must_from_str("0.05"),
+            })
+        );
+        assert!(matches!(
+            guard.check_pyth_price(&pyth_price("0", "0", "98", published), now),
+            Err(OraclePriceError::NonPositivePrice { .. })
+        ));
+
+        // bounds are opt-in
+        assert_eq!(
+            PriceGuard::new(60).check_pyth_price(&pyth_price("100", "2", "80", published), now),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn queries_checked_prices() {
+        let env = mock_env();
+        let now = env.block.time.seconds() as i64;
+        let deps = inj_mock_deps(|querier| {
+            querier.pyth_price_response_handler = create_pyth_price_handler(pyth_price("100", "0.5", "98", now - 120));
+            querier.oracle_price_response_handler = create_oracle_query_handler(
+                FPDecimal::must_from_str("2"),
+                FPDecimal::must_from_str("20"),
+                FPDecimal::must_from_str("10"),
+                FPDecimal::ZERO,
+                FPDecimal::ZERO,
+                now - 10,
+                now - 90,
+            );
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let state = querier.query_pyth_price_checked(PRICE_ID, env.block.time, &PriceGuard::new(300)).unwrap();
+        assert_eq!(state.price_state.price, FPDecimal::must_from_str("100"));
+        assert!(matches!(
+            querier.query_pyth_price_checked(PRICE_ID, env.block.time, &PriceGuard::new(60)),
+            Err(OraclePriceError::StalePrice { age: 120, .. })
+        ));
+
+        let state = querier
+            .query_oracle_price_checked(&OracleType::PriceFeed, "inj", "usdt", None, env.block.time, &PriceGuard::new(120))
+            .unwrap();
+        assert_eq!(state.pair_price, FPDecimal::must_from_str("2"));
+
+        // the quote price is the stale one
+        let err = querier
+            .query_oracle_price_checked(&OracleType::PriceFeed, "inj", "usdt", None, env.block.time, &PriceGuard::new(60))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OraclePriceError::StalePrice {
+                feed: "inj/usdt".to_string(),
+                age: 90,
+                max_age: 60,
+            }
+        );
+
+        // price pairs carry no confidence or EMA to check
+        let err = querier
+            .query_oracle_price_checked(
+                &OracleType::PriceFeed,
+                "inj",
+                "usdt",
+                None,
+                env.block.time,
+                &PriceGuard::new(120).with_max_confidence_ratio(FPDecimal::must_from_str("0.01")),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OraclePriceError::UnsupportedBound {
+                feed: "inj/usdt".to_string(),
+                bound: "max_confidence_ratio".to_string(),
+            }
+        );
+    }
+}
@@ -1,3 +1,6 @@
+pub mod attestation;
+pub mod composite;
+pub mod guard;
 pub mod response;
 pub mod types;
 pub mod volatility;
@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::oracle::types::{PricePairState, PythPriceState};
+use crate::oracle::types::{PricePairState, PriceRecords, PriceState, PythPriceState, StorkPriceState};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OraclePriceResponse {
@@ -12,3 +12,20 @@ pub struct OraclePriceResponse {
 pub struct PythPriceResponse {
     pub price_state: Option<PythPriceState>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct StorkPriceStatesResponse {
+    #[serde(default)]
+    pub price_states: Vec<StorkPriceState>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ProviderPriceStateResponse {
+    pub price_state: Option<PriceState>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct HistoricalPriceRecordsResponse {
+    #[serde(default)]
+    pub price_records: Vec<PriceRecords>,
+}
@@ -1,4 +1,6 @@
-use crate::oracle::volatility::{MetadataStatistics, TradeRecord};
+use crate::exchange::types::Hash;
+use crate::oracle::volatility::{MetadataStatistics, PriceRecord, TradeRecord};
+use cosmwasm_std::Binary;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -29,6 +31,16 @@ pub struct OracleVolatilityResponse {
     pub raw_history: Vec<TradeRecord>,
 }
 
+/// Most recent price records the oracle module keeps for a symbol
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PriceRecords {
+    pub oracle: OracleType,
+    #[serde(alias = "symbolID")]
+    pub symbol_id: String,
+    #[serde(default)]
+    pub latest_price_records: Vec<PriceRecord>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct ScalingOptions {
     pub base_decimals: u8,
@@ -52,13 +64,36 @@ pub enum OracleType {
     Pyth = 9,
     BandIBC = 10,
     Provider = 11,
+    Stork = 12,
+}
+
+impl OracleType {
+    /// Name of the oracle type as defined in the chain's protobuf enum, which is also what market IDs are derived from
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OracleType::Unspecified => "Unspecified",
+            OracleType::Band => "Band",
+            OracleType::PriceFeed => "PriceFeed",
+            OracleType::Coinbase => "Coinbase",
+            OracleType::Chainlink => "Chainlink",
+            OracleType::Razor => "Razor",
+            OracleType::Dia => "Dia",
+            OracleType::API3 => "API3",
+            OracleType::Uma => "Uma",
+            OracleType::Pyth => "Pyth",
+            OracleType::BandIBC => "BandIBC",
+            OracleType::Provider => "Provider",
+            OracleType::Stork => "Stork",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PriceAttestation {
     pub product_id: String,
-    // pub price_id: Hash,
-    pub price_id: String,
+    #[serde(with = "hex_hash")]
+    #[schemars(with = "String")]
+    pub price_id: Hash,
     pub price: i64,
     pub conf: u64,
     pub expo: i32,
@@ -71,6 +106,49 @@ pub struct PriceAttestation {
     pub publish_time: i64,
 }
 
+/// (De)serializes a `Hash` as the `0x` prefixed hex string used by the oracle module
+mod hex_hash {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::exchange::types::Hash;
+
+    pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hash.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Hash::from_hex(s.trim_start_matches("0x")).map_err(de::Error::custom)
+    }
+}
+
+/// Price of an asset signed by a Stork publisher, `timestamp` is in nanoseconds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SignedPriceOfAssetPair {
+    pub publisher_key: String,
+    pub timestamp: u64,
+    pub price: FPDecimal,
+    pub signature: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AssetPair {
+    #[serde(alias = "assetID")]
+    pub asset_id: String,
+    pub signed_prices: Vec<SignedPriceOfAssetPair>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct StorkPriceState {
+    /// Time the price was signed by Stork, in nanoseconds
+    #[serde(default)]
+    pub timestamp: u64,
+    pub symbol: String,
+    #[serde(default)]
+    pub value: FPDecimal,
+    pub price_state: PriceState,
+}
+
 #[derive(Serialize_repr, Deserialize_repr, Clone, Debug, PartialEq, Eq, JsonSchema, Copy)]
 #[repr(i32)]
 pub enum PythStatus {
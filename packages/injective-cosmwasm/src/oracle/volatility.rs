@@ -25,13 +25,186 @@ pub struct TradeHistoryOptions {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PriceRecord {
-    timestamp: i64,
-    price: FPDecimal,
+    pub timestamp: i64,
+    pub price: FPDecimal,
+}
+
+impl PriceRecord {
+    pub fn new(timestamp: i64, price: FPDecimal) -> Self {
+        PriceRecord { timestamp, price }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TradeRecord {
-    timestamp: i64,
-    price: FPDecimal,
-    quantity: FPDecimal,
+    pub timestamp: i64,
+    pub price: FPDecimal,
+    pub quantity: FPDecimal,
+}
+
+impl TradeRecord {
+    pub fn new(timestamp: i64, price: FPDecimal, quantity: FPDecimal) -> Self {
+        TradeRecord { timestamp, price, quantity }
+    }
+}
+
+/// Record of a price at a point in time, implemented by both oracle price records and trade records
+pub trait TimestampedPrice {
+    fn get_timestamp(&self) -> i64;
+    fn get_price(&self) -> FPDecimal;
+}
+
+impl TimestampedPrice for PriceRecord {
+    fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn get_price(&self) -> FPDecimal {
+        self.price
+    }
+}
+
+impl TimestampedPrice for TradeRecord {
+    fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn get_price(&self) -> FPDecimal {
+        self.price
+    }
+}
+
+/// Records with timestamps in `[start, end]`, sorted by timestamp
+fn records_in_window<T: TimestampedPrice>(records: &[T], start: i64, end: i64) -> Vec<&T> {
+    let mut window: Vec<&T> = records
+        .iter()
+        .filter(|record| record.get_timestamp() >= start && record.get_timestamp() <= end)
+        .collect();
+    window.sort_by_key(|record| record.get_timestamp());
+    window
+}
+
+/// Time weighted average price over `[start, end]`. Each price holds until the next record, the last
+/// record before `start` sets the opening price, and the window starts at the first record if there is none.
+/// Returns `None` without any record at or before `end`.
+pub fn time_weighted_average_price<T: TimestampedPrice>(records: &[T], start: i64, end: i64) -> Option<FPDecimal> {
+    if start > end {
+        return None;
+    }
+
+    let opening = records
+        .iter()
+        .filter(|record| record.get_timestamp() < start)
+        .max_by_key(|record| record.get_timestamp());
+    let window = records_in_window(records, start, end);
+
+    let mut points: Vec<(i64, FPDecimal)> = opening.map(|record| (start, record.get_price())).into_iter().collect();
+    points.extend(window.iter().map(|record| (record.get_timestamp(), record.get_price())));
+    let (first_timestamp, last_price) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first.0, last.1),
+        _ => return None,
+    };
+    if first_timestamp == end {
+        return Some(last_price);
+    }
+
+    let weighted_sum = points
+        .iter()
+        .zip(points.iter().skip(1).map(|(timestamp, _)| *timestamp).chain(std::iter::once(end)))
+        .fold(FPDecimal::ZERO, |sum, ((timestamp, price), next_timestamp)| {
+            sum + *price * FPDecimal::from(i128::from(next_timestamp - timestamp))
+        });
+    Some(weighted_sum / FPDecimal::from(i128::from(end - first_timestamp)))
+}
+
+/// Volume weighted average price of the trades in `[start, end]`, `None` without any traded quantity
+pub fn volume_weighted_average_price(trades: &[TradeRecord], start: i64, end: i64) -> Option<FPDecimal> {
+    let window = records_in_window(trades, start, end);
+    let quantity = window.iter().fold(FPDecimal::ZERO, |sum, trade| sum + trade.quantity);
+    if quantity.is_zero() {
+        return None;
+    }
+
+    let notional = window.iter().fold(FPDecimal::ZERO, |sum, trade| sum + trade.price * trade.quantity);
+    Some(notional / quantity)
+}
+
+/// Standard deviation of the simple returns between consecutive records in `[start, end]`, not annualized.
+/// Returns `None` with fewer than two records or a non-positive price.
+pub fn realized_volatility<T: TimestampedPrice>(records: &[T], start: i64, end: i64) -> Option<FPDecimal> {
+    let window = records_in_window(records, start, end);
+    if window.len() < 2 || window.iter().any(|record| record.get_price() <= FPDecimal::ZERO) {
+        return None;
+    }
+
+    let returns: Vec<FPDecimal> = window
+        .windows(2)
+        .map(|pair| pair[1].get_price() / pair[0].get_price() - FPDecimal::ONE)
+        .collect();
+    let count = FPDecimal::from(returns.len() as u128);
+    let mean = returns.iter().fold(FPDecimal::ZERO, |sum, r| sum + *r) / count;
+    let variance = returns.iter().fold(FPDecimal::ZERO, |sum, r| sum + (*r - mean) * (*r - mean)) / count;
+    variance.sqrt().ok()
+}
+
+/// Largest drop from a running peak to a later price in `[start, end]`, relative to the peak, e.g. 0.2 for 20%.
+/// Returns `None` without any record in the window.
+pub fn max_drawdown<T: TimestampedPrice>(records: &[T], start: i64, end: i64) -> Option<FPDecimal> {
+    let window = records_in_window(records, start, end);
+    let first = window.first()?.get_price();
+
+    let (_, drawdown) = window.iter().fold((first, FPDecimal::ZERO), |(peak, drawdown), record| {
+        let price = record.get_price();
+        let peak = peak.maximum(&price);
+        if peak <= FPDecimal::ZERO {
+            return (peak, drawdown);
+        }
+        (peak, drawdown.maximum(&((peak - price) / peak)))
+    });
+    Some(drawdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(prices: &[(i64, &str)]) -> Vec<PriceRecord> {
+        prices
+            .iter()
+            .map(|(timestamp, price)| PriceRecord::new(*timestamp, FPDecimal::must_from_str(price)))
+            .collect()
+    }
+
+    #[test]
+    fn computes_time_weighted_average_price() {
+        let records = records(&[(200, "20"), (100, "10"), (400, "10"), (500, "5")]);
+
+        assert_eq!(time_weighted_average_price(&records, 100, 500), Some(FPDecimal::must_from_str("15")));
+        // the window starts at the first record
+        assert_eq!(time_weighted_average_price(&records, 50, 500), Some(FPDecimal::must_from_str("15")));
+        // the record at 100 sets the opening price at 150
+        assert_eq!(time_weighted_average_price(&records, 150, 400), Some(FPDecimal::must_from_str("18")));
+        assert_eq!(time_weighted_average_price(&records, 500, 500), Some(FPDecimal::must_from_str("5")));
+        assert_eq!(time_weighted_average_price(&records, 0, 50), None);
+        assert_eq!(time_weighted_average_price(&records, 500, 100), None);
+    }
+
+    #[test]
+    fn computes_vwap_volatility_and_drawdown() {
+        let trades = vec![
+            TradeRecord::new(100, FPDecimal::must_from_str("10"), FPDecimal::must_from_str("1")),
+            TradeRecord::new(200, FPDecimal::must_from_str("20"), FPDecimal::must_from_str("3")),
+            TradeRecord::new(300, FPDecimal::must_from_str("50"), FPDecimal::must_from_str("1")),
+        ];
+        assert_eq!(volume_weighted_average_price(&trades, 0, 200), Some(FPDecimal::must_from_str("17.5")));
+        assert_eq!(volume_weighted_average_price(&trades, 400, 500), None);
+
+        let records = records(&[(100, "100"), (200, "110"), (300, "99"), (400, "120")]);
+        assert_eq!(realized_volatility(&records, 100, 300), Some(FPDecimal::must_from_str("0.1")));
+        assert_eq!(realized_volatility(&records, 100, 100), None);
+
+        assert_eq!(max_drawdown(&records, 100, 400), Some(FPDecimal::must_from_str("0.1")));
+        assert_eq!(max_drawdown(&records, 300, 400), Some(FPDecimal::ZERO));
+        assert_eq!(max_drawdown(&records, 500, 600), None);
+    }
 }
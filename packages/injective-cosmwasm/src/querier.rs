@@ -1,6 +1,12 @@
+use crate::authz::{
+    response::{GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse},
+    types::PageRequest,
+};
 use crate::oracle::{
-    response::{OraclePriceResponse, PythPriceResponse},
-    types::{OracleHistoryOptions, OracleInfo, OracleType},
+    composite::{compose_oracle_prices, CompositePrice, OracleHopPrice},
+    guard::{OraclePriceError, PriceGuard},
+    response::{HistoricalPriceRecordsResponse, OraclePriceResponse, ProviderPriceStateResponse, PythPriceResponse, StorkPriceStatesResponse},
+    types::{OracleHistoryOptions, OracleInfo, OracleType, PricePairState, PythPriceState},
     volatility::TradeHistoryOptions,
 };
 use crate::query::{InjectiveQuery, InjectiveQueryWrapper};
@@ -10,19 +16,26 @@ use crate::wasmx::response::QueryContractRegistrationInfoResponse;
 use crate::{
     exchange::{
         cancel::CancellationStrategy,
-        order::OrderSide,
+        derivative::TrimmedDerivativeLimitOrder,
+        market::MarketStatus,
+        order::{GenericTrimmedOrder, OrderSide},
         response::{
-            DerivativeMarketResponse, ExchangeParamsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
+            BinaryOptionsMarketResponse, BinaryOptionsMarketsResponse, DerivativeMarketResponse, DerivativeMarketsResponse,
+            DerivativeOrdersByHashesResponse, ExchangeParamsResponse, FeeDiscountAccountInfoResponse, FeeDiscountScheduleResponse,
+            IsOptedOutOfRewardsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
             PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
-            QueryMarketAtomicExecutionFeeMultiplierResponse, QueryOrderbookResponse, SpotMarketResponse, StakedAmountResponse,
-            SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse,
-            TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
+            QueryMarketAtomicExecutionFeeMultiplierResponse, QueryOrderbookResponse, SpotMarketResponse, SpotMarketsResponse,
+            SpotOrdersByHashesResponse, StakedAmountResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse,
+            SubaccountPositionInMarketResponse, SubaccountPositionsResponse, TradeRewardCampaignResponse, TradeRewardPointsResponse,
+            TraderDerivativeConditionalOrdersResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
         },
-        types::{MarketId, SubaccountId},
+        spot::TrimmedSpotLimitOrder,
+        subaccount::subaccount_ids_for_address,
+        types::{Deposit, MarketId, SubaccountId},
     },
     oracle::types::ScalingOptions,
 };
-use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Timestamp};
 use injective_math::FPDecimal;
 
 pub struct InjectiveQuerier<'a> {
@@ -34,6 +47,48 @@ impl<'a> InjectiveQuerier<'a> {
         InjectiveQuerier { querier }
     }
 
+    // Authz
+    pub fn query_grants(&self, granter: &str, grantee: &str, msg_type_url: &str, pagination: Option<PageRequest>) -> StdResult<GrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::Grants {
+                granter: granter.to_string(),
+                grantee: grantee.to_string(),
+                msg_type_url: msg_type_url.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_grantee_grants(&self, grantee: &str, pagination: Option<PageRequest>) -> StdResult<GranteeGrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::GranteeGrants {
+                grantee: grantee.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GranteeGrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_granter_grants(&self, granter: &str, pagination: Option<PageRequest>) -> StdResult<GranterGrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::GranterGrants {
+                granter: granter.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GranterGrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     // Exchange
     pub fn query_exchange_params(&self) -> StdResult<ExchangeParamsResponse> {
         let request = InjectiveQueryWrapper {
@@ -62,6 +117,25 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    /// Deposits of `denom` in the first `subaccount_count` subaccounts of `trader`, one query per subaccount
+    pub fn query_trader_deposits(&self, trader: &Addr, denom: &str, subaccount_count: u32) -> StdResult<Vec<(SubaccountId, Deposit)>> {
+        subaccount_ids_for_address(trader, subaccount_count)?
+            .into_iter()
+            .map(|subaccount_id| {
+                let request = InjectiveQueryWrapper {
+                    route: InjectiveRoute::Exchange,
+                    query_data: InjectiveQuery::SubaccountDeposit {
+                        subaccount_id: subaccount_id.clone(),
+                        denom: denom.to_string(),
+                    },
+                };
+
+                let res: SubaccountDepositResponse = self.querier.query(&request.into())?;
+                Ok((subaccount_id, res.deposits))
+            })
+            .collect()
+    }
+
     pub fn query_derivative_market<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<DerivativeMarketResponse> {
         let request = InjectiveQueryWrapper {
             route: InjectiveRoute::Exchange,
@@ -74,6 +148,19 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_derivative_markets(&self, status: Option<MarketStatus>, quote_denom: Option<&str>) -> StdResult<DerivativeMarketsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::DerivativeMarkets {
+                status,
+                quote_denom: quote_denom.map(ToString::to_string),
+            },
+        };
+
+        let res: DerivativeMarketsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_spot_market<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<SpotMarketResponse> {
         let request = InjectiveQueryWrapper {
             route: InjectiveRoute::Exchange,
@@ -86,6 +173,19 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_spot_markets(&self, status: Option<MarketStatus>, quote_denom: Option<&str>) -> StdResult<SpotMarketsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SpotMarkets {
+                status,
+                quote_denom: quote_denom.map(ToString::to_string),
+            },
+        };
+
+        let res: SpotMarketsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_effective_subaccount_position<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
         &self,
         market_id: &'a T,
@@ -103,6 +203,18 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_subaccount_positions<T: Into<SubaccountId> + Clone>(&self, subaccount_id: &'a T) -> StdResult<SubaccountPositionsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SubaccountPositions {
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: SubaccountPositionsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_vanilla_subaccount_position<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
         &self,
         market_id: &'a T,
@@ -137,6 +249,62 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_derivative_orders_by_hashes<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
+        &self,
+        market_id: &'a T,
+        subaccount_id: &'a P,
+        order_hashes: Vec<String>,
+    ) -> StdResult<DerivativeOrdersByHashesResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::DerivativeOrdersByHashes {
+                market_id: market_id.clone().into(),
+                subaccount_id: subaccount_id.clone().into(),
+                order_hashes,
+            },
+        };
+
+        let res: DerivativeOrdersByHashesResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Resting derivative orders of the subaccount carrying one of the given cids. The exchange does not index orders
+    /// by cid, so this filters the subaccount's orders in the market.
+    pub fn query_derivative_orders_by_cids<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
+        &self,
+        market_id: &'a T,
+        subaccount_id: &'a P,
+        cids: &[String],
+    ) -> StdResult<Vec<TrimmedDerivativeLimitOrder>> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::TraderDerivativeOrders {
+                market_id: market_id.clone().into(),
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: TraderDerivativeOrdersResponse = self.querier.query(&request.into())?;
+        Ok(filter_orders_by_cids(res.orders.unwrap_or_default(), cids))
+    }
+
+    pub fn query_trader_derivative_conditional_orders<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
+        &self,
+        market_id: &'a T,
+        subaccount_id: &'a P,
+    ) -> StdResult<TraderDerivativeConditionalOrdersResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::TraderDerivativeConditionalOrders {
+                market_id: market_id.clone().into(),
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: TraderDerivativeConditionalOrdersResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_trader_transient_spot_orders<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
         &self,
         market_id: &'a T,
@@ -188,6 +356,45 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_spot_orders_by_hashes<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
+        &self,
+        market_id: &'a T,
+        subaccount_id: &'a P,
+        order_hashes: Vec<String>,
+    ) -> StdResult<SpotOrdersByHashesResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SpotOrdersByHashes {
+                market_id: market_id.clone().into(),
+                subaccount_id: subaccount_id.clone().into(),
+                order_hashes,
+            },
+        };
+
+        let res: SpotOrdersByHashesResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Resting spot orders of the subaccount carrying one of the given cids. The exchange does not index orders
+    /// by cid, so this filters the subaccount's orders in the market.
+    pub fn query_spot_orders_by_cids<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
+        &self,
+        market_id: &'a T,
+        subaccount_id: &'a P,
+        cids: &[String],
+    ) -> StdResult<Vec<TrimmedSpotLimitOrder>> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::TraderSpotOrders {
+                market_id: market_id.clone().into(),
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: TraderSpotOrdersResponse = self.querier.query(&request.into())?;
+        Ok(filter_orders_by_cids(res.orders.unwrap_or_default(), cids))
+    }
+
     pub fn query_spot_orders_to_cancel_up_to_amount<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
         &self,
         market_id: &'a T,
@@ -385,6 +592,116 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_fee_discount_schedule(&self) -> StdResult<FeeDiscountScheduleResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::FeeDiscountSchedule {},
+        };
+
+        let res: FeeDiscountScheduleResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_fee_discount_account_info<T: Into<String> + Clone>(&self, account: &'a T) -> StdResult<FeeDiscountAccountInfoResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::FeeDiscountAccountInfo {
+                account: account.clone().into(),
+            },
+        };
+
+        let res: FeeDiscountAccountInfoResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_is_opted_out_of_rewards<T: Into<String> + Clone>(&self, account: &'a T) -> StdResult<IsOptedOutOfRewardsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::IsOptedOutOfRewards {
+                account: account.clone().into(),
+            },
+        };
+
+        let res: IsOptedOutOfRewardsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_trade_reward_points(&self, accounts: Vec<String>) -> StdResult<TradeRewardPointsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::TradeRewardPoints { accounts },
+        };
+
+        let res: TradeRewardPointsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Points earned in the pending campaign starting at `pending_pool_timestamp`, which become claimable once it ends
+    pub fn query_pending_trade_reward_points(&self, accounts: Vec<String>, pending_pool_timestamp: i64) -> StdResult<TradeRewardPointsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::PendingTradeRewardPoints {
+                accounts,
+                pending_pool_timestamp,
+            },
+        };
+
+        let res: TradeRewardPointsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_trade_reward_campaign(&self) -> StdResult<TradeRewardCampaignResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::TradeRewardCampaign {},
+        };
+
+        let res: TradeRewardCampaignResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_binary_options_market<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<BinaryOptionsMarketResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::BinaryOptionsMarket {
+                market_id: market_id.clone().into(),
+            },
+        };
+
+        let res: BinaryOptionsMarketResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_binary_options_markets(&self, status: Option<MarketStatus>, quote_denom: Option<&str>) -> StdResult<BinaryOptionsMarketsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::BinaryOptionsMarkets {
+                status,
+                quote_denom: quote_denom.map(ToString::to_string),
+            },
+        };
+
+        let res: BinaryOptionsMarketsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_binary_options_market_orderbook<T: Into<MarketId> + Clone>(
+        &self,
+        market_id: &'a T,
+        limit_cumulative_notional: Option<FPDecimal>,
+    ) -> StdResult<QueryOrderbookResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::BinaryOptionsOrderbook {
+                market_id: market_id.clone().into(),
+                limit: 0,
+                limit_cumulative_notional,
+            },
+        };
+        let res: QueryOrderbookResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     // Staking
     pub fn query_staked_amount(&self, delegator_address: Addr, max_delegations: u16) -> StdResult<StakedAmountResponse> {
         let request = InjectiveQueryWrapper {
@@ -458,6 +775,135 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_stork_price_states(&self) -> StdResult<StorkPriceStatesResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Oracle,
+            query_data: InjectiveQuery::StorkPriceStates {},
+        };
+
+        let res: StorkPriceStatesResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Latest price records the oracle module keeps for the symbol, e.g. the base denom or Pyth price id
+    pub fn query_historical_price_records(&self, oracle_type: &OracleType, symbol_id: &str) -> StdResult<HistoricalPriceRecordsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Oracle,
+            query_data: InjectiveQuery::HistoricalPriceRecords {
+                oracle: *oracle_type,
+                symbol_id: symbol_id.to_string(),
+            },
+        };
+
+        let res: HistoricalPriceRecordsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_provider_price_state(&self, provider: &str, symbol: &str) -> StdResult<ProviderPriceStateResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Oracle,
+            query_data: InjectiveQuery::ProviderPriceState {
+                provider: provider.to_string(),
+                symbol: symbol.to_string(),
+            },
+        };
+
+        let res: ProviderPriceStateResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    /// Oracle price pair that was updated within `guard.max_age` seconds of `block_time`
+    pub fn query_oracle_price_checked(
+        &self,
+        oracle_type: &OracleType,
+        base: &str,
+        quote: &str,
+        scaling_options: Option<ScalingOptions>,
+        block_time: Timestamp,
+        guard: &PriceGuard,
+    ) -> Result<PricePairState, OraclePriceError> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Oracle,
+            query_data: InjectiveQuery::OraclePrice {
+                oracle_type: *oracle_type,
+                base: base.into(),
+                quote: quote.into(),
+                scaling_options,
+            },
+        };
+
+        let res: OraclePriceResponse = self.querier.query(&request.into())?;
+        let feed = format!("{base}/{quote}");
+        let state = res.price_pair_state.ok_or_else(|| OraclePriceError::PriceNotFound(feed.to_owned()))?;
+        guard.check_price_pair(&feed, &state, block_time)?;
+
+        Ok(state)
+    }
+
+    /// Pyth price that satisfies the freshness, confidence and EMA deviation bounds of `guard`
+    pub fn query_pyth_price_checked(&self, price_id: &str, block_time: Timestamp, guard: &PriceGuard) -> Result<PythPriceState, OraclePriceError> {
+        let state = self
+            .query_pyth_price(price_id)?
+            .price_state
+            .ok_or_else(|| OraclePriceError::PriceNotFound(price_id.to_string()))?;
+        guard.check_pyth_price(&state, block_time)?;
+
+        Ok(state)
+    }
+
+    /// Price along a path of oracle hops, each inverted if its flag is set. Pyth hops query the symbol as price id,
+    /// other oracles query the pair of a symbol written as `base/quote`.
+    pub fn query_composite_oracle_price(
+        &self,
+        path: &[(OracleInfo, bool)],
+        scaling_options: Option<ScalingOptions>,
+    ) -> Result<CompositePrice, OraclePriceError> {
+        let hops = path
+            .iter()
+            .map(|(oracle_info, invert)| self.query_oracle_hop_price(oracle_info, *invert))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        compose_oracle_prices(&hops, scaling_options.as_ref())
+    }
+
+    fn query_oracle_hop_price(&self, oracle_info: &OracleInfo, invert: bool) -> Result<OracleHopPrice, OraclePriceError> {
+        let feed = oracle_info.symbol.to_owned();
+        let (price, conf, timestamp) = if oracle_info.oracle_type == OracleType::Pyth {
+            let state = self
+                .query_pyth_price(&feed)?
+                .price_state
+                .ok_or_else(|| OraclePriceError::PriceNotFound(feed.to_owned()))?;
+            (state.price_state.price, Some(state.conf), state.publish_time)
+        } else {
+            let (base, quote) = feed
+                .split_once('/')
+                .filter(|(base, quote)| !base.is_empty() && !quote.is_empty())
+                .ok_or_else(|| OraclePriceError::InvalidOracleSymbol(feed.to_owned()))?;
+            let request = InjectiveQueryWrapper {
+                route: InjectiveRoute::Oracle,
+                query_data: InjectiveQuery::OraclePrice {
+                    oracle_type: oracle_info.oracle_type,
+                    base: base.to_string(),
+                    quote: quote.to_string(),
+                    scaling_options: None,
+                },
+            };
+
+            let res: OraclePriceResponse = self.querier.query(&request.into())?;
+            let state = res.price_pair_state.ok_or_else(|| OraclePriceError::PriceNotFound(feed.to_owned()))?;
+            (state.pair_price, None, state.base_timestamp.min(state.quote_timestamp))
+        };
+
+        Ok(OracleHopPrice {
+            feed,
+            price,
+            conf,
+            timestamp,
+            scale_factor: oracle_info.scale_factor,
+            invert,
+        })
+    }
+
     // Tokenfactory
     pub fn query_token_factory_denom_total_supply<T: Into<String> + Clone>(&self, denom: &'a T) -> StdResult<TokenFactoryDenomSupplyResponse> {
         let request = InjectiveQueryWrapper {
@@ -496,3 +942,47 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 }
+
+fn filter_orders_by_cids<O: GenericTrimmedOrder>(orders: Vec<O>, cids: &[String]) -> Vec<O> {
+    orders
+        .into_iter()
+        .filter(|order| order.get_cid().is_some_and(|cid| cids.iter().any(|wanted| wanted == cid)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use injective_math::FPDecimal;
+
+    use crate::exchange_mock_querier::handlers::create_subaccount_deposits_by_subaccount_handler;
+    use crate::test_helpers::testing_helpers::inj_mock_deps;
+    use crate::{Deposit, InjectiveQuerier, SubaccountId};
+
+    #[test]
+    fn queries_trader_deposits_across_subaccounts() {
+        let owner = Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv");
+        let deposit = |amount: u128| Deposit {
+            available_balance: FPDecimal::from(amount),
+            total_balance: FPDecimal::from(amount),
+        };
+        let deposits = vec![
+            (SubaccountId::for_address(&owner, 0).unwrap(), "inj".to_string(), deposit(10)),
+            (SubaccountId::for_address(&owner, 2).unwrap(), "inj".to_string(), deposit(5)),
+            (SubaccountId::for_address(&owner, 2).unwrap(), "usdt".to_string(), deposit(7)),
+        ];
+        let deps = inj_mock_deps(|querier| querier.subaccount_deposit_response_handler = create_subaccount_deposits_by_subaccount_handler(deposits));
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let deposits = querier.query_trader_deposits(&owner, "inj", 3).unwrap();
+        let balances: Vec<(u32, FPDecimal)> = deposits
+            .into_iter()
+            .map(|(subaccount_id, deposit)| (subaccount_id.nonce().unwrap(), deposit.total_balance))
+            .collect();
+        assert_eq!(
+            balances,
+            vec![(0, FPDecimal::from(10u128)), (1, FPDecimal::ZERO), (2, FPDecimal::from(5u128))]
+        );
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use injective_math::FPDecimal;
 
+use crate::authz::types::PageRequest;
 use crate::oracle::{
     types::{OracleHistoryOptions, OracleInfo, OracleType},
     volatility::TradeHistoryOptions,
@@ -12,6 +13,7 @@ use crate::route::InjectiveRoute;
 use crate::{
     exchange::{
         cancel::CancellationStrategy,
+        market::MarketStatus,
         order::OrderSide,
         types::{MarketId, SubaccountId},
     },
@@ -29,6 +31,21 @@ pub struct InjectiveQueryWrapper {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum InjectiveQuery {
+    // Authz
+    Grants {
+        granter: String,
+        grantee: String,
+        msg_type_url: String,
+        pagination: Option<PageRequest>,
+    },
+    GranteeGrants {
+        grantee: String,
+        pagination: Option<PageRequest>,
+    },
+    GranterGrants {
+        granter: String,
+        pagination: Option<PageRequest>,
+    },
     // Exchange
     ExchangeParams {},
     SubaccountDeposit {
@@ -38,10 +55,19 @@ pub enum InjectiveQuery {
     SpotMarket {
         market_id: MarketId,
     },
+    SpotMarkets {
+        status: Option<MarketStatus>,
+        quote_denom: Option<String>,
+    },
     TraderSpotOrders {
         market_id: MarketId,
         subaccount_id: SubaccountId,
     },
+    SpotOrdersByHashes {
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        order_hashes: Vec<String>,
+    },
     TraderSpotOrdersToCancelUpToAmount {
         market_id: MarketId,
         subaccount_id: SubaccountId,
@@ -60,6 +86,10 @@ pub enum InjectiveQuery {
     DerivativeMarket {
         market_id: MarketId,
     },
+    DerivativeMarkets {
+        status: Option<MarketStatus>,
+        quote_denom: Option<String>,
+    },
     SubaccountPositions {
         subaccount_id: SubaccountId,
     },
@@ -75,6 +105,15 @@ pub enum InjectiveQuery {
         market_id: MarketId,
         subaccount_id: SubaccountId,
     },
+    DerivativeOrdersByHashes {
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        order_hashes: Vec<String>,
+    },
+    TraderDerivativeConditionalOrders {
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+    },
     TraderTransientSpotOrders {
         market_id: MarketId,
         subaccount_id: SubaccountId,
@@ -121,6 +160,33 @@ pub enum InjectiveQuery {
     MarketAtomicExecutionFeeMultiplier {
         market_id: MarketId,
     },
+    FeeDiscountSchedule {},
+    FeeDiscountAccountInfo {
+        account: String,
+    },
+    IsOptedOutOfRewards {
+        account: String,
+    },
+    TradeRewardPoints {
+        accounts: Vec<String>,
+    },
+    PendingTradeRewardPoints {
+        accounts: Vec<String>,
+        pending_pool_timestamp: i64,
+    },
+    TradeRewardCampaign {},
+    BinaryOptionsMarket {
+        market_id: MarketId,
+    },
+    BinaryOptionsMarkets {
+        status: Option<MarketStatus>,
+        quote_denom: Option<String>,
+    },
+    BinaryOptionsOrderbook {
+        market_id: MarketId,
+        limit: u64,
+        limit_cumulative_notional: Option<FPDecimal>,
+    },
     // Staking
     StakedAmount {
         delegator_address: Addr,
@@ -141,6 +207,15 @@ pub enum InjectiveQuery {
     PythPrice {
         price_id: String,
     },
+    StorkPriceStates {},
+    HistoricalPriceRecords {
+        oracle: OracleType,
+        symbol_id: String,
+    },
+    ProviderPriceState {
+        provider: String,
+        symbol: String,
+    },
     TokenFactoryDenomTotalSupply {
         denom: String,
     },
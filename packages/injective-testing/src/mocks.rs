@@ -54,6 +54,8 @@ pub fn mock_derivative_market(market_id: &str) -> DerivativeMarket {
         status: MarketStatus::Active,
         min_price_tick_size: FPDecimal::must_from_str("1000.0"),   // 0.001
         min_quantity_tick_size: FPDecimal::must_from_str("0.001"), // 0.001
+        min_notional: FPDecimal::ZERO,
+        relayer_fee_share_rate: Some(FPDecimal::must_from_str("0.4")),
     }
 }
 